@@ -0,0 +1,59 @@
+use football_renter::errors;
+use multiversx_sc_snippets::multiversx_sc_scenario::scenario_model::TxResponseStatus;
+
+// one variant per identifier in the contract's errors module, the identifier is the error message the contract fails with
+macro_rules! contract_errors {
+    ($($variant:ident => $identifier:ident,)*) => {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum FootballRenterError {
+            $($variant,)*
+            // failures that dont come from the contract's require!s, e.g. out of gas or a framework check
+            Other(String),
+        }
+
+        impl FootballRenterError {
+            pub fn from_message(message: &str) -> Self {
+                match message {
+                    $(errors::$identifier => FootballRenterError::$variant,)*
+                    other => FootballRenterError::Other(other.to_string()),
+                }
+            }
+
+            pub fn identifier(&self) -> &str {
+                match self {
+                    $(FootballRenterError::$variant => errors::$identifier,)*
+                    FootballRenterError::Other(message) => message,
+                }
+            }
+        }
+    };
+}
+
+contract_errors! {
+    NotFieldManager => E_NOT_FIELD_MANAGER,
+    CourtCostNotSet => E_COURT_COST_NOT_SET,
+    WrongDeposit => E_WRONG_DEPOSIT,
+    InvalidTimeRange => E_INVALID_TIME_RANGE,
+    SlotOverlap => E_SLOT_OVERLAP,
+    SlotNotFound => E_SLOT_NOT_FOUND,
+    SlotConfirmed => E_SLOT_CONFIRMED,
+    SlotNotConfirmed => E_SLOT_NOT_CONFIRMED,
+    NotSlotCreator => E_NOT_SLOT_CREATOR,
+    AlreadyParticipant => E_ALREADY_PARTICIPANT,
+    NoSlotFunds => E_NO_SLOT_FUNDS,
+    InsufficientRefundFunds => E_INSUFFICIENT_REFUND_FUNDS,
+}
+
+impl std::fmt::Display for FootballRenterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.identifier())
+    }
+}
+
+impl std::error::Error for FootballRenterError {}
+
+impl From<TxResponseStatus> for FootballRenterError {
+    fn from(status: TxResponseStatus) -> Self {
+        FootballRenterError::from_message(&status.message)
+    }
+}
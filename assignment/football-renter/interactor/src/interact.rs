@@ -1,6 +1,7 @@
 #![allow(non_snake_case)]
 
 pub mod config;
+pub mod errors;
 pub mod football_renter_proxy;
 use football_renter_proxy as proxy;
 
@@ -11,3 +11,17 @@ async fn deploy_test_football_renter() {
 
     interactor.deploy().await;
 }
+
+#[test]
+fn contract_error_identifiers_map_to_typed_errors() {
+    use multiversx_sc_snippets::multiversx_sc_scenario::scenario_model::TxResponseStatus;
+    use rust_interact::errors::FootballRenterError;
+
+    let status = TxResponseStatus::new(ReturnCode::UserError, "E_SLOT_NOT_FOUND");
+    assert_eq!(FootballRenterError::from(status), FootballRenterError::SlotNotFound);
+    assert_eq!(FootballRenterError::SlotConfirmed.identifier(), "E_SLOT_CONFIRMED");
+    assert_eq!(
+        FootballRenterError::from_message("out of gas"),
+        FootballRenterError::Other("out of gas".to_string())
+    );
+}
@@ -208,7 +208,7 @@ where
     >(
         self,
         slot_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue6<u64, u64, ManagedAddress<Env::Api>, BigUint<Env::Api>, bool, ManagedAddress<Env::Api>>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue4<Slot<Env::Api>, ManagedVec<Env::Api, ManagedAddress<Env::Api>>, BigUint<Env::Api>, bool>> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("getReservedSlotDetails")
@@ -0,0 +1,20 @@
+// stable error identifiers, every require! in the contract fails with one of these as its message
+// clients match on them so they never get renamed or reused for another failure
+
+// field manager
+pub const E_NOT_FIELD_MANAGER: &str = "E_NOT_FIELD_MANAGER";
+pub const E_COURT_COST_NOT_SET: &str = "E_COURT_COST_NOT_SET";
+
+// slots
+pub const E_WRONG_DEPOSIT: &str = "E_WRONG_DEPOSIT";
+pub const E_INVALID_TIME_RANGE: &str = "E_INVALID_TIME_RANGE";
+pub const E_SLOT_OVERLAP: &str = "E_SLOT_OVERLAP";
+pub const E_SLOT_NOT_FOUND: &str = "E_SLOT_NOT_FOUND";
+pub const E_SLOT_CONFIRMED: &str = "E_SLOT_CONFIRMED";
+pub const E_SLOT_NOT_CONFIRMED: &str = "E_SLOT_NOT_CONFIRMED";
+pub const E_NOT_SLOT_CREATOR: &str = "E_NOT_SLOT_CREATOR";
+pub const E_ALREADY_PARTICIPANT: &str = "E_ALREADY_PARTICIPANT";
+
+// payouts
+pub const E_NO_SLOT_FUNDS: &str = "E_NO_SLOT_FUNDS";
+pub const E_INSUFFICIENT_REFUND_FUNDS: &str = "E_INSUFFICIENT_REFUND_FUNDS";
@@ -6,9 +6,12 @@ use multiversx_sc::{derive_imports::*};
 
 pub type SlotId = u64;
 
+pub mod errors;
 mod events;
 mod storage;
 
+use errors::*;
+
 #[type_abi]
 #[derive(TopEncode, TopDecode,NestedEncode,NestedDecode, Debug)]
 pub struct Slot<M: ManagedTypeApi>{
@@ -63,7 +66,7 @@ pub trait FootballRenter: events::FootbalEvents{
         let caller = self.blockchain().get_caller();
         require!(
             caller == self.field_manager_address().get(),
-            "only the field manager can change the minimum deposit"
+            E_NOT_FIELD_MANAGER
         );
         self.minimum_deposit().set(amount);  
     }
@@ -78,12 +81,12 @@ pub trait FootballRenter: events::FootbalEvents{
 
         require!(
             *deposit_amount == minimum_deposit,
-            "the deposit must be at least equal or bigger than the deposit requiremt."
+            E_WRONG_DEPOSIT
         );
 
         require!(
             start_time < end_time,
-            "start time cant be higher than end time"
+            E_INVALID_TIME_RANGE
         );
 
 
@@ -123,12 +126,12 @@ pub trait FootballRenter: events::FootbalEvents{
 
         require!(
             *deposit_amount == minimum_deposit,
-            "the deposit must be at least equal or bigger than the deposit requiremt."
+            E_WRONG_DEPOSIT
         );
 
         require!(
             !self.reserved_slots(slot_id).is_empty(),
-            "the slot doesnt exist"
+            E_SLOT_NOT_FOUND
         );
 
 
@@ -136,14 +139,14 @@ pub trait FootballRenter: events::FootbalEvents{
 
         require!(
             !slot.confirmed,
-            "slot is confirmed cant join anymore"
+            E_SLOT_CONFIRMED
         );
 
         let mut participants_mapper = self.participants(slot_id);
 
         require!(
             !participants_mapper.contains(&caller),
-            "you are already a participant in this slot"
+            E_ALREADY_PARTICIPANT
         );
 
         participants_mapper.insert(caller.clone());
@@ -162,19 +165,19 @@ pub trait FootballRenter: events::FootbalEvents{
 
         require!(
             !self.reserved_slots(slot_id).is_empty(),
-            "the slot doesnt exit"
+            E_SLOT_NOT_FOUND
         );
 
         let slot = self.reserved_slots(slot_id).get();
 
         require!(
             caller == slot.initiator_address,
-            "only the slot creator can cancel slots"
+            E_NOT_SLOT_CREATOR
         );
 
         require!(
             !slot.confirmed,
-            "slot has been confirmed already cannot cancel"
+            E_SLOT_CONFIRMED
         );
 
 
@@ -185,7 +188,7 @@ pub trait FootballRenter: events::FootbalEvents{
         let participants_addreses = participants_mapper.iter().collect::<ManagedVec<Self::Api, ManagedAddress<Self::Api>>>();
         
         for participants_address in participants_addreses.into_iter(){
-            require!(slot.amount >= &total_refunded + &min_deposit, "Not enough funds for full refund");
+            require!(slot.amount >= &total_refunded + &min_deposit, E_INSUFFICIENT_REFUND_FUNDS);
 
             self.send().direct_egld(&participants_address, &min_deposit);
             total_refunded += min_deposit.clone();  // or use clone  
@@ -213,7 +216,7 @@ pub trait FootballRenter: events::FootbalEvents{
 
         require!(
             caller == previous_manager,
-            "the caller must be the previous manager only he can change the manager; old manager(caller) -> new manager "
+            E_NOT_FIELD_MANAGER
         );
 
         self.field_manager_address().set(new_manager.clone());
@@ -228,33 +231,33 @@ pub trait FootballRenter: events::FootbalEvents{
     fn pay_court(&self, slot_id: SlotId){
         let caller = self.blockchain().get_caller();
         let manager_address = self.field_manager_address().get();
-        let mut slot= self.reserved_slots(slot_id).get();
 
         require!(
             caller == manager_address,
-            "only the field manager can transfer trigger the payment"
+            E_NOT_FIELD_MANAGER
         );
 
         require!(
             !self.reserved_slots(slot_id).is_empty(),
-            "the slot doesnt exit"
+            E_SLOT_NOT_FOUND
         );
+        let mut slot= self.reserved_slots(slot_id).get();
 
         require!(
             slot.confirmed,
-            "the slot has to be confirmed first"
+            E_SLOT_NOT_CONFIRMED
         );
 
         let payment_amount = slot.amount.clone();
         require!(
             payment_amount > BigUint::zero(),
-            "no funds found for the selected slot"
+            E_NO_SLOT_FUNDS
         );
         
         let court_cost = self.court_cost().get();
         require!(
             court_cost > BigUint::zero(),
-            "the court cost must be set"
+            E_COURT_COST_NOT_SET
         );
 
         if payment_amount < court_cost {
@@ -270,7 +273,7 @@ pub trait FootballRenter: events::FootbalEvents{
             let participants_addreses = participants_mapper.iter().collect::<ManagedVec<Self::Api, ManagedAddress<Self::Api>>>();
             
             for participants_address in participants_addreses.into_iter(){
-                require!(slot.amount >= &total_refunded + &min_deposit, "Not enough funds for full refund");
+                require!(slot.amount >= &total_refunded + &min_deposit, E_INSUFFICIENT_REFUND_FUNDS);
 
                 self.send().direct_egld(&participants_address, &min_deposit);
                 total_refunded += min_deposit.clone();
@@ -306,7 +309,7 @@ pub trait FootballRenter: events::FootbalEvents{
         let caller = self.blockchain().get_caller();
         require!(
             caller == self.field_manager_address().get(),
-            "the caller isnt a manager he got no power for this action"
+            E_NOT_FIELD_MANAGER
         );
         self.court_cost().set(cost);
     
@@ -316,18 +319,18 @@ pub trait FootballRenter: events::FootbalEvents{
     #[endpoint(confirmSlot)]
     fn confirm_slot(&self, slot_id: SlotId){
         let caller = self.blockchain().get_caller();
-        let mut slot = self.reserved_slots(slot_id).get();
         require!(
             caller == self.field_manager_address().get(),
-            "the caller isnt a manager he got no power for this action"
+            E_NOT_FIELD_MANAGER
         );
         require!(
             !self.reserved_slots(slot_id).is_empty(),
-            "the slot doesnt exit"
+            E_SLOT_NOT_FOUND
         );
+        let mut slot = self.reserved_slots(slot_id).get();
         require!(
             !slot.confirmed,
-            "the slot is already confirmed"
+            E_SLOT_CONFIRMED
         );
 
         slot.confirmed = true;
@@ -339,32 +342,32 @@ pub trait FootballRenter: events::FootbalEvents{
     #[endpoint(getSlotStatus)]
     fn get_slot_status(&self, slot_id: SlotId) -> MultiValue4<Slot<Self::Api>, ManagedVec<Self::Api, ManagedAddress<Self::Api>>, BigUint<Self::Api>, bool>
     {
+        require!(
+            !self.reserved_slots(slot_id).is_empty(),
+            E_SLOT_NOT_FOUND
+        );
+
         let slot = self.reserved_slots(slot_id).get();
         let participants = self.participants(slot_id).iter().collect();
         let amount = slot.amount.clone();
         let confirmed = slot.confirmed;
 
-        require!(
-            !self.reserved_slots(slot_id).is_empty(),
-            "the slot doesnt exit"
-        );
-
         (slot,participants,amount,confirmed).into()
     }
 
     #[view(getReservedSlotDetails)]  
     fn get_reserved_slot_details(&self, slot_id: SlotId) -> MultiValue4<Slot<Self::Api>, ManagedVec<Self::Api, ManagedAddress<Self::Api>>, BigUint<Self::Api>, bool>
     {
+        require!(
+            !self.reserved_slots(slot_id).is_empty(),
+            E_SLOT_NOT_FOUND
+        );
+
         let slot = self.reserved_slots(slot_id).get();  
         let participants = self.participants(slot_id).iter().collect();
         let amount = slot.amount.clone();
         let confirmed = slot.confirmed;
 
-        require!(
-            !self.reserved_slots(slot_id).is_empty(),
-            "the slot doesnt exit"
-        );
-
         (slot,participants,amount,confirmed).into()  
     }
 
@@ -382,7 +385,7 @@ pub trait FootballRenter: events::FootbalEvents{
 
                 require!(
                     !does_overlap,
-                    "time conflict: overlapping"
+                    E_SLOT_OVERLAP
                 );
             }
         }
@@ -16,14 +16,40 @@ WORKER_5="WORKERS_WALLETS/new_WORKER_5.pem"
 
 
 DOCKER_IMAGE_URI="TEST_DOCKER_IMAGE"
+IMAGE_DIGEST="sha256:0000000000000000000000000000000000000000000000000000000000000000" # content hash of the image, see `docker inspect --format='{{index .RepoDigests 0}}'`
 INPUT_DATA_URI="TEST_INPUT_DATA"
+ENTRYPOINT="" # empty runs the image default
+ARGUMENTS="" # space separated
+OUTPUT_FORMAT="json"
+CPU_MILLICORES=1000 # resource hints, 0 means no preference
+MEMORY_MB=512
+TIME_LIMIT_SECONDS=600
+RESULT_ENCODING_VERSION=1
 MAX_WORKERS=5
+CONSENSUS_POLICY=0 # nested encoded ConsensusPolicy: 0 = simple majority, 0x02 = unanimity, 0x03<count as 4 bytes> = quorum
+TASK_DURATION=3600 # seconds workers have to commit their result
+REVEAL_DURATION=600 # seconds workers have to reveal after the commit phase, the task can be expired afterwards
+LEASE_DURATION=1800 # seconds a seat reserved with claimTask stays reserved
+SALT="salt" # secret appended to the result hash when committing
+PARTICIPATION_BPS=0 # basis points of the reward split between every worker that revealed, whatever the outcome
+MIN_REPUTATION=0 # 0 lets any worker submit, otherwise only registered workers with at least this reputation
+WORKER_STAKE=0 # EGLD (in wei) each worker locks when claiming a seat or submitting a result
+ESCALATION_EXTRA_WORKERS=0 # seats added when the revealed votes dont reach consensus
+ESCALATION_MAX_ROUNDS=0 # 0 fails the task instead of escalating
+ESCALATION_RESERVE_BPS=0 # basis points of the reward held back for the escalation rounds
+ESCALATION=0x$(printf "%08x%08x%016x" ${ESCALATION_EXTRA_WORKERS} ${ESCALATION_MAX_ROUNDS} ${ESCALATION_RESERVE_BPS}) # nested encoded EscalationPolicy
+DISPUTE_PERIOD=0 # seconds the payouts are held after consensus so the creator can dispute the result, 0 pays out right away
+ALLOW_LIST="" # space separated worker addresses (bech32), together with WORKER_GROUP empty means anyone can work on the task
+WORKER_GROUP="" # name of one of your worker groups, see add_workers_to_group
+PARENT_TASK_IDS="" # space separated, the task stays pending until all of them completed
 
 
 
 
 deploy() {
-    mxpy --verbose contract deploy --bytecode=${PROJECT} --pem=${GABI} --gas-limit 50000000 --send --outfile="deploy-devnet.interaction.json" --proxy=${PROXY} || return
+    read -p "Enter treasury address: " TREASURY
+    read -p "Enter protocol fee (basis points): " PROTOCOL_FEE_BPS
+    mxpy --verbose contract deploy --bytecode=${PROJECT} --pem=${GABI} --gas-limit 50000000 --arguments ${TREASURY} ${PROTOCOL_FEE_BPS} --send --outfile="deploy-devnet.interaction.json" --proxy=${PROXY} || return
 
     TRANSACTION=$(mxpy data parse --file="deploy-devnet.interaction.json" --expression="data['emittedTransactionHash']")
     ADDRESS=$(mxpy data parse --file="deploy-devnet.interaction.json" --expression="data['contractAddress']")
@@ -35,26 +61,335 @@ deploy() {
     echo "Smart contract address: ${ADDRESS}"
 }
 
+add_accepted_token() {
+    read -p "Enter token identifier: " TOKEN_ID
+
+    mxpy --verbose contract call ${ADDRESS} \
+        --pem=${GABI} \
+        --gas-limit 10000000 \
+        --function="addAcceptedToken" \
+        --arguments "str:${TOKEN_ID}" \
+        --proxy=${PROXY} \
+        --send
+}
+
+# nested encoded TaskAccess built from ALLOW_LIST and WORKER_GROUP
+task_access() {
+    python3 - "${WORKER_GROUP}" ${ALLOW_LIST} <<'EOF'
+import sys
+from multiversx_sdk import Address
+group, workers = sys.argv[1].encode(), sys.argv[2:]
+access = len(workers).to_bytes(4, "big") + b"".join(Address.new_from_bech32(worker).get_public_key() for worker in workers)
+access += len(group).to_bytes(4, "big") + group
+print(access.hex())
+EOF
+}
+
+# nested encoded TaskSpec built from the variables above
+task_spec() {
+    python3 - "${DOCKER_IMAGE_URI}" "${IMAGE_DIGEST}" "${INPUT_DATA_URI}" "${ENTRYPOINT}" "${ARGUMENTS}" "${OUTPUT_FORMAT}" ${CPU_MILLICORES} ${MEMORY_MB} ${TIME_LIMIT_SECONDS} ${RESULT_ENCODING_VERSION} <<'EOF'
+import sys
+image, digest, input_data, entrypoint, arguments, output_format = sys.argv[1:7]
+cpu, memory, time_limit, version = (int(value) for value in sys.argv[7:11])
+buffer = lambda value: len(value.encode()).to_bytes(4, "big") + value.encode()
+arguments = arguments.split()
+spec = buffer(image) + buffer(digest) + buffer(input_data) + buffer(entrypoint)
+spec += len(arguments).to_bytes(4, "big") + b"".join(buffer(argument) for argument in arguments)
+spec += buffer(output_format) + cpu.to_bytes(4, "big") + memory.to_bytes(4, "big") + time_limit.to_bytes(8, "big") + version.to_bytes(1, "big")
+print(spec.hex())
+EOF
+}
+
 post_task(){
     read -p "Enter reward amount (in wei): " EGLD_AMOUNT  
-    mxpy --verbose contract call ${ADDRESS} --pem=${GABI} --gas-limit 10000000 --function="postTask" --arguments "str:${DOCKER_IMAGE_URI}" "str:${INPUT_DATA_URI}" ${MAX_WORKERS} --value=${EGLD_AMOUNT} --proxy=${PROXY} --send  
+    mxpy --verbose contract call ${ADDRESS} --pem=${GABI} --gas-limit 10000000 --function="postTask" --arguments 0x$(task_spec) ${MAX_WORKERS} ${CONSENSUS_POLICY} ${TASK_DURATION} ${REVEAL_DURATION} ${LEASE_DURATION} ${WORKER_STAKE} ${MIN_REPUTATION} ${PARTICIPATION_BPS} ${ESCALATION} ${DISPUTE_PERIOD} 0x$(task_access) ${PARENT_TASK_IDS} --value=${EGLD_AMOUNT} --proxy=${PROXY} --send  
+
+}
+
+# one task per shard, same image and settings: post_tasks <input uri> <reward share in wei> [<input uri> <reward share in wei> ...]
+# the payment is the sum of the shares
+post_tasks() {
+    ENTRIES=()
+    EGLD_AMOUNT=0
+    while [ $# -ge 2 ]; do
+        ENTRIES+=(0x$(INPUT_DATA_URI=$1 task_spec) ${MAX_WORKERS} $2)
+        EGLD_AMOUNT=$(python3 -c "print($EGLD_AMOUNT + $2)")
+        shift 2
+    done
 
+    mxpy --verbose contract call ${ADDRESS} \
+        --pem=${GABI} \
+        --gas-limit 100000000 \
+        --function="postTasks" \
+        --arguments ${CONSENSUS_POLICY} ${TASK_DURATION} ${REVEAL_DURATION} ${LEASE_DURATION} ${WORKER_STAKE} ${MIN_REPUTATION} ${PARTICIPATION_BPS} ${ESCALATION} ${DISPUTE_PERIOD} 0x$(task_access) ${ENTRIES[@]} \
+        --value=${EGLD_AMOUNT} \
+        --proxy=${PROXY} \
+        --send
+}
+
+increase_reward() {
+    read -p "Enter task id: " TASK_ID
+    read -p "Enter amount to add (in wei): " EGLD_AMOUNT
+
+    mxpy --verbose contract call ${ADDRESS} \
+        --pem=${GABI} \
+        --gas-limit 10000000 \
+        --function="increaseReward" \
+        --arguments ${TASK_ID} \
+        --value=${EGLD_AMOUNT} \
+        --proxy=${PROXY} \
+        --send
+}
+
+claim_task() {
+    TASK_ID=$1
+    WORKER_PEM=$2
+
+    mxpy --verbose contract call ${ADDRESS} \
+        --pem=${WORKER_PEM} \
+        --gas-limit 10000000 \
+        --function="claimTask" \
+        --arguments ${TASK_ID} \
+        --value=${WORKER_STAKE} \
+        --proxy=${PROXY} \
+        --send
+}
+
+free_seats() {
+    read -p "Enter task id: " TASK_ID
+
+    mxpy contract query ${ADDRESS} \
+        --function="getFreeSeats" \
+        --arguments ${TASK_ID} \
+        --proxy=${PROXY}
+}
+
+# commitment <result hash> <salt> <worker pem>
+# keccak256(keccak256(result_hash) || salt || worker address), computed with pycryptodome and the sdk which come with mxpy
+commitment() {
+    python3 - "$1" "$2" "$3" <<'EOF'
+import sys
+from pathlib import Path
+from Crypto.Hash import keccak
+from multiversx_sdk import UserPEM
+
+worker = UserPEM.from_file(Path(sys.argv[3])).public_key.buffer
+result_hash = keccak.new(digest_bits=256, data=sys.argv[1].encode()).digest()
+print(keccak.new(digest_bits=256, data=result_hash + sys.argv[2].encode() + worker).hexdigest())
+EOF
 }
 
 submit_result() {
     TASK_ID=$1
     WORKER_PEM=$2
     RESULT_HASH=$3
+    STAKE=${4:-${WORKER_STAKE}} # 0 if the seat was reserved with claim_task, the lease already holds the stake
+    COMMITMENT=$(commitment ${RESULT_HASH} ${SALT} ${WORKER_PEM})
 
     mxpy --verbose contract call ${ADDRESS} \
         --pem=${WORKER_PEM} \
         --gas-limit 10000000 \
         --function="submitResult" \
-        --arguments ${TASK_ID} "str:${RESULT_HASH}" \
+        --arguments ${TASK_ID} 0x${COMMITMENT} \
+        --value=${STAKE} \
+        --proxy=${PROXY} \
+        --send
+}
+
+reveal_result() {
+    TASK_ID=$1
+    WORKER_PEM=$2
+    RESULT_HASH=$3
+    OUTPUT_URI=$4 # optional, where the output behind the hash can be fetched
+
+    mxpy --verbose contract call ${ADDRESS} \
+        --pem=${WORKER_PEM} \
+        --gas-limit 10000000 \
+        --function="revealResult" \
+        --arguments ${TASK_ID} "str:${RESULT_HASH}" "str:${SALT}" "str:${OUTPUT_URI}" \
+        --proxy=${PROXY} \
+        --send
+}
+
+parent_results() {
+    read -p "Enter task id: " TASK_ID
+
+    mxpy contract query ${ADDRESS} \
+        --function="getParentResults" \
+        --arguments ${TASK_ID} \
+        --proxy=${PROXY}
+}
+
+task_result() {
+    read -p "Enter task id: " TASK_ID
+
+    mxpy contract query ${ADDRESS} \
+        --function="getTaskResult" \
+        --arguments ${TASK_ID} \
+        --proxy=${PROXY}
+}
+
+
+# add_workers_to_group <group name> <worker address> [<worker address> ...]
+add_workers_to_group() {
+    GROUP=$1
+    shift
+
+    mxpy --verbose contract call ${ADDRESS} \
+        --pem=${GABI} \
+        --gas-limit 10000000 \
+        --function="addWorkersToGroup" \
+        --arguments "str:${GROUP}" $@ \
+        --proxy=${PROXY} \
+        --send
+}
+
+# add_to_allow_list <task id> <worker address> [<worker address> ...]
+add_to_allow_list() {
+    TASK_ID=$1
+    shift
+
+    mxpy --verbose contract call ${ADDRESS} \
+        --pem=${GABI} \
+        --gas-limit 10000000 \
+        --function="addToAllowList" \
+        --arguments ${TASK_ID} $@ \
+        --proxy=${PROXY} \
+        --send
+}
+
+# set_encryption_key <worker pem> <hex public key>
+set_encryption_key() {
+    WORKER_PEM=$1
+    PUBLIC_KEY=$2
+
+    mxpy --verbose contract call ${ADDRESS} \
+        --pem=${WORKER_PEM} \
+        --gas-limit 10000000 \
+        --function="setEncryptionKey" \
+        --arguments 0x${PUBLIC_KEY} \
+        --proxy=${PROXY} \
+        --send
+}
+
+# attach_input_key <task id> <worker address> <hex ciphertext>, the worker needs a seat (claim_task or submit_result)
+attach_input_key() {
+    TASK_ID=$1
+    WORKER_ADDRESS=$2
+    ENCRYPTED_KEY=$3
+
+    mxpy --verbose contract call ${ADDRESS} \
+        --pem=${GABI} \
+        --gas-limit 10000000 \
+        --function="attachInputKeys" \
+        --arguments ${TASK_ID} ${WORKER_ADDRESS} 0x${ENCRYPTED_KEY} \
+        --proxy=${PROXY} \
+        --send
+}
+
+encrypted_input_key() {
+    read -p "Enter task id: " TASK_ID
+    read -p "Enter worker address: " WORKER_ADDRESS
+
+    mxpy contract query ${ADDRESS} \
+        --function="getEncryptedInputKey" \
+        --arguments ${TASK_ID} ${WORKER_ADDRESS} \
+        --proxy=${PROXY}
+}
+
+register_worker() {
+    WORKER_PEM=$1
+    METADATA=$2
+
+    mxpy --verbose contract call ${ADDRESS} \
+        --pem=${WORKER_PEM} \
+        --gas-limit 10000000 \
+        --function="registerWorker" \
+        --arguments "str:${METADATA}" \
+        --proxy=${PROXY} \
+        --send
+}
+
+worker_stats() {
+    read -p "Enter worker address: " WORKER_ADDRESS
+
+    mxpy contract query ${ADDRESS} \
+        --function="getWorkerStats" \
+        --arguments ${WORKER_ADDRESS} \
+        --proxy=${PROXY}
+}
+
+
+pause() {
+    mxpy --verbose contract call ${ADDRESS} --pem=${GABI} --gas-limit 10000000 --function="pause" --proxy=${PROXY} --send
+}
+
+unpause() {
+    mxpy --verbose contract call ${ADDRESS} --pem=${GABI} --gas-limit 10000000 --function="unpause" --proxy=${PROXY} --send
+}
+
+set_protocol_fee() {
+    read -p "Enter protocol fee (basis points): " PROTOCOL_FEE_BPS
+    mxpy --verbose contract call ${ADDRESS} --pem=${GABI} --gas-limit 10000000 --function="setProtocolFee" --arguments ${PROTOCOL_FEE_BPS} --proxy=${PROXY} --send
+}
+
+set_treasury() {
+    read -p "Enter treasury address: " TREASURY
+    mxpy --verbose contract call ${ADDRESS} --pem=${GABI} --gas-limit 10000000 --function="setTreasury" --arguments ${TREASURY} --proxy=${PROXY} --send
+}
+
+dispute_result() {
+    read -p "Enter task id: " TASK_ID
+    read -p "Enter dispute bond (in wei): " BOND
+
+    mxpy --verbose contract call ${ADDRESS} --pem=${GABI} --gas-limit 10000000 --function="disputeResult" --arguments ${TASK_ID} --value=${BOND} --proxy=${PROXY} --send
+}
+
+# resolve_dispute <arbiter pem> <task id> <true to reverse the result, false to confirm it>
+resolve_dispute() {
+    ARBITER_PEM=$1
+    TASK_ID=$2
+    REVERSE_RESULT=$3
+
+    mxpy --verbose contract call ${ADDRESS} --pem=${ARBITER_PEM} --gas-limit 50000000 --function="resolveDispute" --arguments ${TASK_ID} ${REVERSE_RESULT} --proxy=${PROXY} --send
+}
+
+settle_task() {
+    read -p "Enter task id: " TASK_ID
+
+    mxpy --verbose contract call ${ADDRESS} --pem=${GABI} --gas-limit 50000000 --function="settleTask" --arguments ${TASK_ID} --proxy=${PROXY} --send
+}
+
+set_arbiter() {
+    read -p "Enter arbiter address: " ARBITER
+    mxpy --verbose contract call ${ADDRESS} --pem=${GABI} --gas-limit 10000000 --function="setArbiter" --arguments ${ARBITER} --proxy=${PROXY} --send
+}
+
+set_dispute_bond() {
+    read -p "Enter dispute bond (in wei): " BOND
+    mxpy --verbose contract call ${ADDRESS} --pem=${GABI} --gas-limit 10000000 --function="setDisputeBond" --arguments ${BOND} --proxy=${PROXY} --send
+}
+
+claim_rewards() {
+    WORKER_PEM=$1
+
+    mxpy --verbose contract call ${ADDRESS} \
+        --pem=${WORKER_PEM} \
+        --gas-limit 10000000 \
+        --function="claimRewards" \
         --proxy=${PROXY} \
         --send
 }
 
+claimable() {
+    read -p "Enter address: " CLAIM_ADDRESS
+
+    mxpy contract query ${ADDRESS} \
+        --function="getClaimable" \
+        --arguments ${CLAIM_ADDRESS} \
+        --proxy=${PROXY}
+}
+
 
 check_task() {
     read -p "Enter task id: " TASK_ID  
@@ -66,6 +401,59 @@ check_task() {
 }
 
 
+cancel_task() {
+    read -p "Enter task id: " TASK_ID
+
+    mxpy --verbose contract call ${ADDRESS} \
+        --pem=${GABI} \
+        --gas-limit 10000000 \
+        --function="cancelTask" \
+        --arguments ${TASK_ID} \
+        --proxy=${PROXY} \
+        --send
+}
+
+
+expire_task() {
+    read -p "Enter task id: " TASK_ID
+
+    mxpy --verbose contract call ${ADDRESS} \
+        --pem=${GABI} \
+        --gas-limit 10000000 \
+        --function="expireTask" \
+        --arguments ${TASK_ID} \
+        --proxy=${PROXY} \
+        --send
+}
+
+
+# opens a pending task once its parents completed, or fails it if one of them did not
+activate_task() {
+    read -p "Enter task id: " TASK_ID
+
+    mxpy --verbose contract call ${ADDRESS} \
+        --pem=${GABI} \
+        --gas-limit 10000000 \
+        --function="activateTask" \
+        --arguments ${TASK_ID} \
+        --proxy=${PROXY} \
+        --send
+}
+
+# clears the per-worker storage of a finished task in batches, prints how many workers are left
+prune_task() {
+    read -p "Enter task id: " TASK_ID
+
+    mxpy --verbose contract call ${ADDRESS} \
+        --pem=${GABI} \
+        --gas-limit 30000000 \
+        --function="pruneTask" \
+        --arguments ${TASK_ID} \
+        --proxy=${PROXY} \
+        --send
+}
+
+
 task_status(){
 
     read -p "Enter task id: " TASK_ID  
@@ -89,6 +477,13 @@ simulate_task() {
     submit_result ${TASK_ID} ${WORKER_4} "resultA"
     submit_result ${TASK_ID} ${WORKER_5} "resultA"
 
+    echo "Revealing results..."
+    reveal_result ${TASK_ID} ${WORKER_1} "resultA"
+    reveal_result ${TASK_ID} ${WORKER_2} "resultA"
+    reveal_result ${TASK_ID} ${WORKER_3} "resultB"
+    reveal_result ${TASK_ID} ${WORKER_4} "resultA"
+    reveal_result ${TASK_ID} ${WORKER_5} "resultA"
+
     echo "Checking final task status..."
     task_status ${TASK_ID}
 }
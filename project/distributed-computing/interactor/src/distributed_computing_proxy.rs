@@ -43,12 +43,19 @@ where
     From: TxFrom<Env>,
     Gas: TxGas<Env>,
 {
-    pub fn init(
+    pub fn init<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
         self,
+        treasury: Arg0,
+        protocol_fee_bps: Arg1,
     ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_deploy()
+            .argument(&treasury)
+            .argument(&protocol_fee_bps)
             .original_result()
     }
 }
@@ -81,37 +88,926 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
+    pub fn task_id_counter(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTaskIdCounter")
+            .original_result()
+    }
+
+    pub fn accepted_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAcceptedTokens")
+            .original_result()
+    }
+
+    pub fn add_accepted_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addAcceptedToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn remove_accepted_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeAcceptedToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
     pub fn post_task<
-        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
-        Arg2: ProxyArg<usize>,
+        Arg0: ProxyArg<TaskSpec<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ConsensusPolicy>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<u64>,
+        Arg6: ProxyArg<BigUint<Env::Api>>,
+        Arg7: ProxyArg<u64>,
+        Arg8: ProxyArg<u64>,
+        Arg9: ProxyArg<EscalationPolicy>,
+        Arg10: ProxyArg<u64>,
+        Arg11: ProxyArg<TaskAccess<Env::Api>>,
+        Arg12: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
     >(
         self,
-        docker_image_uri: Arg0,
-        input_data_uri: Arg1,
-        max_workers: Arg2,
+        spec: Arg0,
+        max_workers: Arg1,
+        consensus_policy: Arg2,
+        duration: Arg3,
+        reveal_duration: Arg4,
+        lease_duration: Arg5,
+        worker_stake: Arg6,
+        min_reputation: Arg7,
+        participation_bps: Arg8,
+        escalation: Arg9,
+        dispute_period: Arg10,
+        access: Arg11,
+        parent_task_ids: Arg12,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
             .raw_call("postTask")
-            .argument(&docker_image_uri)
-            .argument(&input_data_uri)
+            .argument(&spec)
             .argument(&max_workers)
+            .argument(&consensus_policy)
+            .argument(&duration)
+            .argument(&reveal_duration)
+            .argument(&lease_duration)
+            .argument(&worker_stake)
+            .argument(&min_reputation)
+            .argument(&participation_bps)
+            .argument(&escalation)
+            .argument(&dispute_period)
+            .argument(&access)
+            .argument(&parent_task_ids)
+            .original_result()
+    }
+
+    pub fn post_tasks<
+        Arg0: ProxyArg<ConsensusPolicy>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
+        Arg5: ProxyArg<u64>,
+        Arg6: ProxyArg<u64>,
+        Arg7: ProxyArg<EscalationPolicy>,
+        Arg8: ProxyArg<u64>,
+        Arg9: ProxyArg<TaskAccess<Env::Api>>,
+        Arg10: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<TaskSpec<Env::Api>, usize, BigUint<Env::Api>>>>,
+    >(
+        self,
+        consensus_policy: Arg0,
+        duration: Arg1,
+        reveal_duration: Arg2,
+        lease_duration: Arg3,
+        worker_stake: Arg4,
+        min_reputation: Arg5,
+        participation_bps: Arg6,
+        escalation: Arg7,
+        dispute_period: Arg8,
+        access: Arg9,
+        entries: Arg10,
+    ) -> TxTypedCall<Env, From, To, (), Gas, MultiValue2<u64, u64>> {
+        self.wrapped_tx
+            .raw_call("postTasks")
+            .argument(&consensus_policy)
+            .argument(&duration)
+            .argument(&reveal_duration)
+            .argument(&lease_duration)
+            .argument(&worker_stake)
+            .argument(&min_reputation)
+            .argument(&participation_bps)
+            .argument(&escalation)
+            .argument(&dispute_period)
+            .argument(&access)
+            .argument(&entries)
+            .original_result()
+    }
+
+    pub fn cancel_task<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        task_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelTask")
+            .argument(&task_id)
+            .original_result()
+    }
+
+    pub fn increase_reward<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        task_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("increaseReward")
+            .argument(&task_id)
+            .original_result()
+    }
+
+    pub fn add_to_allow_list<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        task_id: Arg0,
+        workers: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addToAllowList")
+            .argument(&task_id)
+            .argument(&workers)
+            .original_result()
+    }
+
+    pub fn remove_from_allow_list<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        task_id: Arg0,
+        workers: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeFromAllowList")
+            .argument(&task_id)
+            .argument(&workers)
+            .original_result()
+    }
+
+    pub fn set_task_worker_group<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        task_id: Arg0,
+        worker_group: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTaskWorkerGroup")
+            .argument(&task_id)
+            .argument(&worker_group)
+            .original_result()
+    }
+
+    pub fn attach_input_keys<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, ManagedBuffer<Env::Api>>>>,
+    >(
+        self,
+        task_id: Arg0,
+        keys: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("attachInputKeys")
+            .argument(&task_id)
+            .argument(&keys)
+            .original_result()
+    }
+
+    pub fn dispute_result<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        task_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("disputeResult")
+            .argument(&task_id)
+            .original_result()
+    }
+
+    pub fn resolve_dispute<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        task_id: Arg0,
+        reverse_result: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resolveDispute")
+            .argument(&task_id)
+            .argument(&reverse_result)
+            .original_result()
+    }
+
+    pub fn settle_task<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        task_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("settleTask")
+            .argument(&task_id)
+            .original_result()
+    }
+
+    pub fn activate_task<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        task_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("activateTask")
+            .argument(&task_id)
+            .original_result()
+    }
+
+    pub fn prune_task<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        task_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pruneTask")
+            .argument(&task_id)
+            .original_result()
+    }
+
+    pub fn claim_task<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        task_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("claimTask")
+            .argument(&task_id)
             .original_result()
     }
 
     pub fn submit_result<
         Arg0: ProxyArg<u64>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        task_id: Arg0,
+        commitment: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("submitResult")
+            .argument(&task_id)
+            .argument(&commitment)
+            .original_result()
+    }
+
+    pub fn reveal_result<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
         self,
         task_id: Arg0,
         result_hash: Arg1,
+        salt: Arg2,
+        output_uri: Arg3,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("submitResult")
+            .raw_call("revealResult")
             .argument(&task_id)
             .argument(&result_hash)
+            .argument(&salt)
+            .argument(&output_uri)
+            .original_result()
+    }
+
+    pub fn expire_task<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        task_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("expireTask")
+            .argument(&task_id)
+            .original_result()
+    }
+
+    pub fn claim_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimRewards")
+            .original_result()
+    }
+
+    pub fn get_task<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        task_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Task<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTask")
+            .argument(&task_id)
+            .original_result()
+    }
+
+    pub fn get_task_status<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        task_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TaskStatus> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTaskStatus")
+            .argument(&task_id)
             .original_result()
     }
+
+    pub fn get_task_result<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        task_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TaskResult<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTaskResult")
+            .argument(&task_id)
+            .original_result()
+    }
+
+    pub fn get_reward_contributions<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        task_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardContributions")
+            .argument(&task_id)
+            .original_result()
+    }
+
+    pub fn get_task_allow_list<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        task_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTaskAllowList")
+            .argument(&task_id)
+            .original_result()
+    }
+
+    pub fn get_encrypted_input_key<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        task_id: Arg0,
+        worker: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEncryptedInputKey")
+            .argument(&task_id)
+            .argument(&worker)
+            .original_result()
+    }
+
+    pub fn get_parent_results<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        task_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, TaskResult<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getParentResults")
+            .argument(&task_id)
+            .original_result()
+    }
+
+    pub fn get_free_seats<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        task_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFreeSeats")
+            .argument(&task_id)
+            .original_result()
+    }
+
+    pub fn get_tasks<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from_task_id: Arg0,
+        limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, Task<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTasks")
+            .argument(&from_task_id)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_tasks_by_status<
+        Arg0: ProxyArg<TaskStatus>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        status: Arg0,
+        offset: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, Task<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTasksByStatus")
+            .argument(&status)
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_tasks_by_creator<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        creator: Arg0,
+        offset: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, Task<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTasksByCreator")
+            .argument(&creator)
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_tasks_by_worker<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        worker: Arg0,
+        offset: Arg1,
+        limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, Task<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTasksByWorker")
+            .argument(&worker)
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_claimable<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimable")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_funds_audit<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFundsAudit")
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+
+    pub fn protocol_fee_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolFee")
+            .original_result()
+    }
+
+    pub fn treasury(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTreasury")
+            .original_result()
+    }
+
+    pub fn arbiter(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getArbiter")
+            .original_result()
+    }
+
+    pub fn dispute_bond(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDisputeBond")
+            .original_result()
+    }
+
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    pub fn set_protocol_fee<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        protocol_fee_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setProtocolFee")
+            .argument(&protocol_fee_bps)
+            .original_result()
+    }
+
+    pub fn set_treasury<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        treasury: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTreasury")
+            .argument(&treasury)
+            .original_result()
+    }
+
+    pub fn set_arbiter<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        arbiter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setArbiter")
+            .argument(&arbiter)
+            .original_result()
+    }
+
+    pub fn set_dispute_bond<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        dispute_bond: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDisputeBond")
+            .argument(&dispute_bond)
+            .original_result()
+    }
+
+    pub fn register_worker<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        metadata: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerWorker")
+            .argument(&metadata)
+            .original_result()
+    }
+
+    pub fn worker_encryption_keys<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        worker: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWorkerEncryptionKey")
+            .argument(&worker)
+            .original_result()
+    }
+
+    pub fn set_encryption_key<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        public_key: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEncryptionKey")
+            .argument(&public_key)
+            .original_result()
+    }
+
+    pub fn add_workers_to_group<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        name: Arg0,
+        workers: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addWorkersToGroup")
+            .argument(&name)
+            .argument(&workers)
+            .original_result()
+    }
+
+    pub fn remove_workers_from_group<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        name: Arg0,
+        workers: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeWorkersFromGroup")
+            .argument(&name)
+            .argument(&workers)
+            .original_result()
+    }
+
+    pub fn get_worker_group<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        manager: Arg0,
+        name: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWorkerGroup")
+            .argument(&manager)
+            .argument(&name)
+            .original_result()
+    }
+
+    pub fn get_worker_stats<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        worker: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, WorkerStats<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWorkerStats")
+            .argument(&worker)
+            .original_result()
+    }
+
+    pub fn get_worker_reputation<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        worker: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWorkerReputation")
+            .argument(&worker)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Debug, PartialEq)]
+pub struct TaskSpec<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub docker_image_uri: ManagedBuffer<Api>,
+    pub image_digest: ManagedBuffer<Api>,
+    pub input_data_uri: ManagedBuffer<Api>,
+    pub entrypoint: ManagedBuffer<Api>,
+    pub arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+    pub output_format: ManagedBuffer<Api>,
+    pub resources: ResourceHints,
+    pub result_encoding_version: u8,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Debug, PartialEq)]
+pub struct ResourceHints {
+    pub cpu_millicores: u32,
+    pub memory_mb: u32,
+    pub time_limit_seconds: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Clone, Debug, PartialEq)]
+pub enum ConsensusPolicy {
+    SimpleMajority,
+    Supermajority(Fraction),
+    Unanimity,
+    Quorum(usize),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Clone, Debug, PartialEq)]
+pub struct Fraction {
+    pub numerator: u32,
+    pub denominator: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Clone, Debug, PartialEq)]
+pub struct EscalationPolicy {
+    pub extra_workers: usize,
+    pub max_rounds: u32,
+    pub reserve_bps: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Clone, Debug, PartialEq)]
+pub struct TaskAccess<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub allow_list: ManagedVec<Api, ManagedAddress<Api>>,
+    pub worker_group: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Debug, PartialEq)]
+pub struct Task<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub creator: ManagedAddress<Api>,
+    pub spec: TaskSpec<Api>,
+    pub reward_token: EgldOrEsdtTokenIdentifier<Api>,
+    pub reward_amount: BigUint<Api>,
+    pub max_workers: usize,
+    pub consensus_policy: ConsensusPolicy,
+    pub submissions_count: usize,
+    pub revealed_count: usize,
+    pub status: TaskStatus,
+    pub commit_deadline: u64,
+    pub deadline: u64,
+    pub lease_duration: u64,
+    pub worker_stake: BigUint<Api>,
+    pub min_reputation: u64,
+    pub protocol_fee_bps: u64,
+    pub participation_bps: u64,
+    pub parent_task_ids: ManagedVec<Api, u64>,
+    pub escalation: EscalationPolicy,
+    pub escalation_round: u32,
+    pub private: bool,
+    pub worker_group: ManagedBuffer<Api>,
+    pub dispute_period: u64,
+    pub dispute_deadline: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Debug, PartialEq)]
+pub enum TaskStatus {
+    Open,
+    InVerification,
+    Completed,
+    Failed,
+    Expired,
+    Cancelled,
+    Pending,
+    Verified,
+    Disputed,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug, PartialEq)]
+pub struct TaskResult<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub result_hash: ManagedBuffer<Api>,
+    pub output_uris: ManagedVec<Api, ManagedBuffer<Api>>,
+    pub agreeing_workers: ManagedVec<Api, ManagedAddress<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug, PartialEq)]
+pub struct WorkerStats<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub registered: bool,
+    pub metadata: ManagedBuffer<Api>,
+    pub submissions: u64,
+    pub wins: u64,
+    pub losses: u64,
+    pub expired_commitments: u64,
 }
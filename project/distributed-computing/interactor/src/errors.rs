@@ -0,0 +1,113 @@
+use distributed_computing::errors;
+use multiversx_sc_snippets::multiversx_sc_scenario::scenario_model::TxResponseStatus;
+
+// one variant per identifier in the contract's errors module, the identifier is the error message the contract fails with
+macro_rules! contract_errors {
+    ($($variant:ident => $identifier:ident,)*) => {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum DistributedComputingError {
+            $($variant,)*
+            // failures that dont come from the contract's require!s, e.g. out of gas or the owner-only check
+            Other(String),
+        }
+
+        impl DistributedComputingError {
+            pub fn from_message(message: &str) -> Self {
+                match message {
+                    $(errors::$identifier => DistributedComputingError::$variant,)*
+                    other => DistributedComputingError::Other(other.to_string()),
+                }
+            }
+
+            pub fn identifier(&self) -> &str {
+                match self {
+                    $(DistributedComputingError::$variant => errors::$identifier,)*
+                    DistributedComputingError::Other(message) => message,
+                }
+            }
+        }
+    };
+}
+
+contract_errors! {
+    Paused => E_PAUSED,
+    InvalidProtocolFee => E_INVALID_PROTOCOL_FEE,
+    InvalidToken => E_INVALID_TOKEN,
+    ZeroReward => E_ZERO_REWARD,
+    TokenNotAccepted => E_TOKEN_NOT_ACCEPTED,
+    WrongPaymentToken => E_WRONG_PAYMENT_TOKEN,
+    NoWorkers => E_NO_WORKERS,
+    TooManyWorkers => E_TOO_MANY_WORKERS,
+    IncompatiblePolicy => E_INCOMPATIBLE_POLICY,
+    InvalidDuration => E_INVALID_DURATION,
+    InvalidRevealDuration => E_INVALID_REVEAL_DURATION,
+    InvalidLeaseDuration => E_INVALID_LEASE_DURATION,
+    InvalidParticipation => E_INVALID_PARTICIPATION,
+    InvalidEscalation => E_INVALID_ESCALATION,
+    InvalidEscalationReserve => E_INVALID_ESCALATION_RESERVE,
+    InvalidImageUri => E_INVALID_IMAGE_URI,
+    InvalidImageDigest => E_INVALID_IMAGE_DIGEST,
+    InvalidInputUri => E_INVALID_INPUT_URI,
+    InvalidEntrypoint => E_INVALID_ENTRYPOINT,
+    TooManyArguments => E_TOO_MANY_ARGUMENTS,
+    InvalidArgument => E_INVALID_ARGUMENT,
+    InvalidOutputFormat => E_INVALID_OUTPUT_FORMAT,
+    InvalidGroupName => E_INVALID_GROUP_NAME,
+    TooManyParents => E_TOO_MANY_PARENTS,
+    ParentNotFound => E_PARENT_NOT_FOUND,
+    DuplicateParent => E_DUPLICATE_PARENT,
+    ParentFailed => E_PARENT_FAILED,
+    ParentsNotFinished => E_PARENTS_NOT_FINISHED,
+    EmptyBatch => E_EMPTY_BATCH,
+    SharesMismatch => E_SHARES_MISMATCH,
+    TooManySponsors => E_TOO_MANY_SPONSORS,
+    InvalidEncryptedKey => E_INVALID_ENCRYPTED_KEY,
+    TaskNotFound => E_TASK_NOT_FOUND,
+    TaskNotOpen => E_TASK_NOT_OPEN,
+    TaskNotPending => E_TASK_NOT_PENDING,
+    NotTaskCreator => E_NOT_TASK_CREATOR,
+    CommitPhaseEnded => E_COMMIT_PHASE_ENDED,
+    RevealNotStarted => E_REVEAL_NOT_STARTED,
+    DeadlinePassed => E_DEADLINE_PASSED,
+    DeadlineNotPassed => E_DEADLINE_NOT_PASSED,
+    TaskNotFinished => E_TASK_NOT_FINISHED,
+    NoResult => E_NO_RESULT,
+    ReputationTooLow => E_REPUTATION_TOO_LOW,
+    WorkerNotAllowed => E_WORKER_NOT_ALLOWED,
+    WorkerNotAssigned => E_WORKER_NOT_ASSIGNED,
+    NoEncryptionKey => E_NO_ENCRYPTION_KEY,
+    InvalidEncryptionKey => E_INVALID_ENCRYPTION_KEY,
+    LeaseAlreadyHeld => E_LEASE_ALREADY_HELD,
+    NoFreeSeats => E_NO_FREE_SEATS,
+    WrongStake => E_WRONG_STAKE,
+    DuplicateSubmission => E_DUPLICATE_SUBMISSION,
+    DuplicateCommitment => E_DUPLICATE_COMMITMENT,
+    NoCommitment => E_NO_COMMITMENT,
+    DuplicateReveal => E_DUPLICATE_REVEAL,
+    EmptyResultHash => E_EMPTY_RESULT_HASH,
+    InvalidOutputUri => E_INVALID_OUTPUT_URI,
+    CommitmentMismatch => E_COMMITMENT_MISMATCH,
+    NothingToClaim => E_NOTHING_TO_CLAIM,
+    TaskNotVerified => E_TASK_NOT_VERIFIED,
+    TaskNotDisputed => E_TASK_NOT_DISPUTED,
+    DisputePeriodEnded => E_DISPUTE_PERIOD_ENDED,
+    DisputePeriodActive => E_DISPUTE_PERIOD_ACTIVE,
+    ArbitrationPeriodActive => E_ARBITRATION_PERIOD_ACTIVE,
+    NoArbiter => E_NO_ARBITER,
+    NotArbiter => E_NOT_ARBITER,
+    WrongDisputeBond => E_WRONG_DISPUTE_BOND,
+}
+
+impl std::fmt::Display for DistributedComputingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.identifier())
+    }
+}
+
+impl std::error::Error for DistributedComputingError {}
+
+impl From<TxResponseStatus> for DistributedComputingError {
+    fn from(status: TxResponseStatus) -> Self {
+        DistributedComputingError::from_message(&status.message)
+    }
+}
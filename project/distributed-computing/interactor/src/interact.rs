@@ -1,6 +1,7 @@
 #![allow(non_snake_case)]
 
 pub mod config;
+pub mod errors;
 mod proxy;
 
 use config::Config;
@@ -25,7 +26,13 @@ pub async fn distributed_computing_cli() {
         "deploy" => interact.deploy().await,
         "upgrade" => interact.upgrade().await,
         "postTask" => interact.post_task().await,
+        "postTasks" => interact.post_tasks().await,
+        "increaseReward" => interact.increase_reward().await,
+        "activateTask" => interact.activate_task().await,
+        "pruneTask" => interact.prune_task().await,
         "submitResult" => interact.submit_result().await,
+        "revealResult" => interact.reveal_result().await,
+        "claimRewards" => interact.claim_rewards().await,
         _ => panic!("unknown command: {}", &cmd),
     }
 }
@@ -110,7 +117,7 @@ impl ContractInteract {
             .from(&self.wallet_address)
             .gas(30_000_000u64)
             .typed(proxy::DistributedComputingProxy)
-            .init()
+            .init(&self.wallet_address, 0u64)
             .code(&self.contract_code)
             .returns(ReturnsNewAddress)
             .run()
@@ -141,9 +148,39 @@ impl ContractInteract {
     pub async fn post_task(&mut self) {
         let egld_amount = BigUint::<StaticApi>::from(0u128);
 
-        let docker_image_uri = ManagedBuffer::new_from_bytes(&b""[..]);
-        let input_data_uri = ManagedBuffer::new_from_bytes(&b""[..]);
+        let spec = proxy::TaskSpec {
+            docker_image_uri: ManagedBuffer::new_from_bytes(&b""[..]),
+            image_digest: ManagedBuffer::new_from_bytes(&b""[..]),
+            input_data_uri: ManagedBuffer::new_from_bytes(&b""[..]),
+            entrypoint: ManagedBuffer::new_from_bytes(&b""[..]),
+            arguments: ManagedVec::new(),
+            output_format: ManagedBuffer::new_from_bytes(&b""[..]),
+            resources: proxy::ResourceHints {
+                cpu_millicores: 0u32,
+                memory_mb: 0u32,
+                time_limit_seconds: 0u64,
+            },
+            result_encoding_version: 0u8,
+        };
         let max_workers = 0u32;
+        let consensus_policy = proxy::ConsensusPolicy::SimpleMajority;
+        let duration = 0u64;
+        let reveal_duration = 0u64;
+        let lease_duration = 0u64;
+        let worker_stake = BigUint::<StaticApi>::from(0u128);
+        let min_reputation = 0u64;
+        let participation_bps = 0u64;
+        let escalation = proxy::EscalationPolicy {
+            extra_workers: 0usize,
+            max_rounds: 0u32,
+            reserve_bps: 0u64,
+        };
+        let dispute_period = 0u64;
+        let access = proxy::TaskAccess {
+            allow_list: ManagedVec::new(),
+            worker_group: ManagedBuffer::new_from_bytes(&b""[..]),
+        };
+        let parent_task_ids = MultiValueVec::<u64>::new();
 
         let response = self
             .interactor
@@ -152,7 +189,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::DistributedComputingProxy)
-            .post_task(docker_image_uri, input_data_uri, max_workers)
+            .post_task(spec, max_workers, consensus_policy, duration, reveal_duration, lease_duration, worker_stake, min_reputation, participation_bps, escalation, dispute_period, access, parent_task_ids)
             .egld(egld_amount)
             .returns(ReturnsResultUnmanaged)
             .run()
@@ -161,10 +198,156 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 
+    pub async fn post_tasks(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let consensus_policy = proxy::ConsensusPolicy::SimpleMajority;
+        let duration = 0u64;
+        let reveal_duration = 0u64;
+        let lease_duration = 0u64;
+        let worker_stake = BigUint::<StaticApi>::from(0u128);
+        let min_reputation = 0u64;
+        let participation_bps = 0u64;
+        let escalation = proxy::EscalationPolicy {
+            extra_workers: 0usize,
+            max_rounds: 0u32,
+            reserve_bps: 0u64,
+        };
+        let dispute_period = 0u64;
+        let access = proxy::TaskAccess {
+            allow_list: ManagedVec::new(),
+            worker_group: ManagedBuffer::new_from_bytes(&b""[..]),
+        };
+        let spec = proxy::TaskSpec {
+            docker_image_uri: ManagedBuffer::new_from_bytes(&b""[..]),
+            image_digest: ManagedBuffer::new_from_bytes(&b""[..]),
+            input_data_uri: ManagedBuffer::new_from_bytes(&b""[..]),
+            entrypoint: ManagedBuffer::new_from_bytes(&b""[..]),
+            arguments: ManagedVec::new(),
+            output_format: ManagedBuffer::new_from_bytes(&b""[..]),
+            resources: proxy::ResourceHints {
+                cpu_millicores: 0u32,
+                memory_mb: 0u32,
+                time_limit_seconds: 0u64,
+            },
+            result_encoding_version: 0u8,
+        };
+        let entries = MultiValueVec::from(vec![MultiValue3::from((spec, 0u32, BigUint::<StaticApi>::from(0u128)))]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::DistributedComputingProxy)
+            .post_tasks(consensus_policy, duration, reveal_duration, lease_duration, worker_stake, min_reputation, participation_bps, escalation, dispute_period, access, entries)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn increase_reward(&mut self) {
+        let egld_amount = BigUint::<StaticApi>::from(0u128);
+
+        let task_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::DistributedComputingProxy)
+            .increase_reward(task_id)
+            .egld(egld_amount)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn activate_task(&mut self) {
+        let task_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::DistributedComputingProxy)
+            .activate_task(task_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn prune_task(&mut self) {
+        let task_id = 0u64;
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::DistributedComputingProxy)
+            .prune_task(task_id)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
     pub async fn submit_result(&mut self) {
+        let task_id = 0u64;
+        let commitment = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::DistributedComputingProxy)
+            .submit_result(task_id, commitment)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
+
+    pub async fn reveal_result(&mut self) {
         let task_id = 0u64;
         let result_hash = ManagedBuffer::new_from_bytes(&b""[..]);
+        let salt = ManagedBuffer::new_from_bytes(&b""[..]);
+        let output_uri = ManagedBuffer::new_from_bytes(&b""[..]);
+
+        let response = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(self.state.current_address())
+            .gas(30_000_000u64)
+            .typed(proxy::DistributedComputingProxy)
+            .reveal_result(task_id, result_hash, salt, output_uri)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .await;
+
+        println!("Result: {response:?}");
+    }
 
+    pub async fn claim_rewards(&mut self) {
         let response = self
             .interactor
             .tx()
@@ -172,7 +355,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::DistributedComputingProxy)
-            .submit_result(task_id, result_hash)
+            .claim_rewards()
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -11,3 +11,17 @@ async fn deploy_test_distributed_computing() {
 
     interactor.deploy().await;
 }
+
+#[test]
+fn contract_error_identifiers_map_to_typed_errors() {
+    use multiversx_sc_snippets::multiversx_sc_scenario::scenario_model::TxResponseStatus;
+    use rust_interact::errors::DistributedComputingError;
+
+    let status = TxResponseStatus::new(ReturnCode::UserError, "E_TASK_NOT_FOUND");
+    assert_eq!(DistributedComputingError::from(status), DistributedComputingError::TaskNotFound);
+    assert_eq!(DistributedComputingError::DuplicateSubmission.identifier(), "E_DUPLICATE_SUBMISSION");
+    assert_eq!(
+        DistributedComputingError::from_message("out of gas"),
+        DistributedComputingError::Other("out of gas".to_string())
+    );
+}
@@ -0,0 +1,82 @@
+#[allow(unused_imports)]
+use multiversx_sc::imports::*;
+
+use crate::errors::*;
+
+pub const MAX_BPS: u64 = 10_000;
+
+
+#[multiversx_sc::module]
+pub trait AdminModule{
+
+    #[view(isPaused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    // basis points of the reward kept by the protocol when a task reaches consensus
+    #[view(getProtocolFee)]
+    #[storage_mapper("protocol_fee_bps")]
+    fn protocol_fee_bps(&self) -> SingleValueMapper<u64>;
+
+    #[view(getTreasury)]
+    #[storage_mapper("treasury")]
+    fn treasury(&self) -> SingleValueMapper<ManagedAddress>;
+
+    // resolves the disputes opened by creators, no disputes can be opened until one is appointed
+    #[view(getArbiter)]
+    #[storage_mapper("arbiter")]
+    fn arbiter(&self) -> SingleValueMapper<ManagedAddress>;
+
+    // EGLD a creator locks to dispute a result, returned if the result is reversed
+    #[view(getDisputeBond)]
+    #[storage_mapper("dispute_bond")]
+    fn dispute_bond(&self) -> SingleValueMapper<BigUint>;
+
+
+    // blocks new tasks, new leases and submissions without a lease, lease holders can still commit and
+    // reveals/withdrawals/expiry keep working so nobody's funds or stakes get stuck
+    #[only_owner]
+    #[endpoint(pause)]
+    fn pause(&self){
+        self.paused().set(true);
+    }
+
+    #[only_owner]
+    #[endpoint(unpause)]
+    fn unpause(&self){
+        self.paused().set(false);
+    }
+
+    #[only_owner]
+    #[endpoint(setProtocolFee)]
+    fn set_protocol_fee(&self, protocol_fee_bps: u64){
+        require!(
+            protocol_fee_bps <= MAX_BPS, E_INVALID_PROTOCOL_FEE
+        );
+        self.protocol_fee_bps().set(protocol_fee_bps);
+    }
+
+    #[only_owner]
+    #[endpoint(setTreasury)]
+    fn set_treasury(&self, treasury: ManagedAddress){
+        self.treasury().set(treasury);
+    }
+
+    #[only_owner]
+    #[endpoint(setArbiter)]
+    fn set_arbiter(&self, arbiter: ManagedAddress){
+        self.arbiter().set(arbiter);
+    }
+
+    #[only_owner]
+    #[endpoint(setDisputeBond)]
+    fn set_dispute_bond(&self, dispute_bond: BigUint){
+        self.dispute_bond().set(dispute_bond);
+    }
+
+    fn require_not_paused(&self){
+        require!(
+            !self.paused().get(), E_PAUSED
+        );
+    }
+}
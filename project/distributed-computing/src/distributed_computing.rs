@@ -2,12 +2,35 @@
 
 // use core::task;
 
-use core::task;
 
 #[allow(unused_imports)]
 use multiversx_sc::imports::*;
 use multiversx_sc::{derive_imports::*};
 
+mod admin;
+pub mod errors;
+mod events;
+mod workers;
+
+use errors::*;
+
+pub const MAX_PAGE_SIZE: usize = 100;
+pub const MAX_WORKERS: usize = 50; // seats per task including escalation, finalization walks every worker in a single call
+
+// upper bounds for the job specification, keeps task storage and getTask responses bounded
+pub const MAX_URI_LENGTH: usize = 256;
+pub const MAX_ENTRYPOINT_LENGTH: usize = 256;
+pub const MAX_ARGUMENTS: usize = 32;
+pub const MAX_ARGUMENT_LENGTH: usize = 256;
+pub const MAX_OUTPUT_FORMAT_LENGTH: usize = 64;
+pub const IMAGE_DIGEST_PREFIX: &[u8] = b"sha256:";
+pub const IMAGE_DIGEST_LENGTH: usize = 71; // "sha256:" followed by 64 lowercase hex characters
+pub const MAX_PARENT_TASKS: usize = 32;
+pub const MAX_SPONSORS: usize = 20;
+pub const MAX_ENCRYPTED_KEY_LENGTH: usize = 512;
+pub const ARBITRATION_PERIOD: u64 = 7 * 24 * 60 * 60; // seconds the arbiter has to resolve a dispute, afterwards anyone can confirm the result
+pub const PRUNE_BATCH_SIZE: usize = 20; // workers cleared per pruneTask call, each one costs several storage writes
+
 
 
 #[type_abi]
@@ -17,27 +40,147 @@ pub enum TaskStatus {
     InVerification,
     Completed,
     Failed,
+    Expired, // the deadline passed before consensus could be reached, creator got refunded
+    Cancelled, // withdrawn by the creator while still open
+    Pending, // waiting for its parent tasks to complete, the commit phase starts once they all did
+    Verified, // consensus reached, the payouts are held until the dispute period ends
+    Disputed, // the creator challenged the result, waiting for the arbiter
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Clone, Debug, PartialEq)]
+pub struct Fraction {
+    pub numerator: u32,
+    pub denominator: u32,
+}
+
+// how many matching votes a result hash needs to win, chosen by the creator when posting
+#[type_abi]
+#[derive(TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Clone, Debug, PartialEq)]
+pub enum ConsensusPolicy {
+    SimpleMajority, // more than half of the votes
+    Supermajority(Fraction), // at least numerator/denominator of the votes, the fraction must be above 1/2
+    Unanimity, // every vote
+    Quorum(usize), // a fixed number of votes, must be more than max_workers / 2
+}
+
+impl ConsensusPolicy {
+    // a policy is only valid if two different hashes can never both reach it
+    pub fn is_compatible(&self, max_workers: usize) -> bool {
+        match self {
+            ConsensusPolicy::SimpleMajority | ConsensusPolicy::Unanimity => true,
+            ConsensusPolicy::Supermajority(fraction) => {
+                fraction.denominator > 0 && fraction.numerator <= fraction.denominator && 2 * fraction.numerator as u64 > fraction.denominator as u64
+            },
+            ConsensusPolicy::Quorum(count) => *count > max_workers / 2 && *count <= max_workers,
+        }
+    }
+
+    // counted against every seat, not only the revealed ones, so an expired task with missing reveals
+    // needs as many matching votes as a full one and a single vote can never win a larger task
+    pub fn required_votes(&self, max_workers: usize) -> usize {
+        let required = match self {
+            ConsensusPolicy::SimpleMajority => (max_workers / 2) + 1,
+            ConsensusPolicy::Supermajority(fraction) => {
+                // rounded up, so the winning share is never below the fraction
+                (max_workers as u64 * fraction.numerator as u64).div_ceil(fraction.denominator as u64) as usize
+            },
+            ConsensusPolicy::Unanimity => max_workers,
+            ConsensusPolicy::Quorum(count) => *count,
+        };
+        core::cmp::max(required, 1)
+    }
 }
 
+// hints for the worker runtime, 0 means no preference
 #[type_abi]
 #[derive(TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Debug, PartialEq)]
-pub struct Task<M: ManagedTypeApi>{
-    pub creator: ManagedAddress<M>,
+pub struct ResourceHints {
+    pub cpu_millicores: u32,
+    pub memory_mb: u32,
+    pub time_limit_seconds: u64,
+}
+
+// everything a worker needs to run the job and produce a comparable result
+#[type_abi]
+#[derive(TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Debug, PartialEq)]
+pub struct TaskSpec<M: ManagedTypeApi>{
     pub docker_image_uri: ManagedBuffer<M>,
+    pub image_digest: ManagedBuffer<M>, // content hash of the image ("sha256:<hex>"), workers must run exactly this image whatever the tag points to
     pub input_data_uri: ManagedBuffer<M>,
+    pub entrypoint: ManagedBuffer<M>, // empty means the image default
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+    pub output_format: ManagedBuffer<M>, // e.g. "json", "csv", "binary"
+    pub resources: ResourceHints,
+    pub result_encoding_version: u8, // version of the convention used to hash the output into result_hash
+}
+
+// restricts a task to chosen workers, leave both empty for a public task
+#[type_abi]
+#[derive(TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Clone, Debug, PartialEq)]
+pub struct TaskAccess<M: ManagedTypeApi>{
+    pub allow_list: ManagedVec<M, ManagedAddress<M>>,
+    pub worker_group: ManagedBuffer<M>, // name of one of the creator's worker groups
+}
+
+// instead of failing when the revealed votes dont reach consensus, the task re-opens for more workers
+#[type_abi]
+#[derive(TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Clone, Debug, PartialEq)]
+pub struct EscalationPolicy {
+    pub extra_workers: usize, // seats added every round
+    pub max_rounds: u32, // 0 disables escalation
+    pub reserve_bps: u64, // basis points of the reward held back for the extra rounds, what belongs to rounds that didnt happen is refunded
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, ManagedVecItem, NestedEncode, NestedDecode, Debug, PartialEq)]
+pub struct Task<M: ManagedTypeApi>{
+    pub creator: ManagedAddress<M>,
+    pub spec: TaskSpec<M>,
+    pub reward_token: EgldOrEsdtTokenIdentifier<M>, // EGLD or one of the accepted ESDTs, rewards and refunds are paid in this token
     pub reward_amount: BigUint<M>,
     pub max_workers: usize,
+    pub consensus_policy: ConsensusPolicy,
     pub submissions_count: usize, // the number of workers posting a submission, if this is less than the max_workers that means free space is available for others to participate
+    pub revealed_count: usize, // how many of the submitted commitments were revealed, only these count as votes
     pub status: TaskStatus,
+    pub commit_deadline: u64, // block timestamp (seconds) until which workers can commit, reveals are accepted afterwards (or as soon as all seats are taken)
+    pub deadline: u64, // block timestamp (seconds) after which no more reveals are accepted and the task can be expired
+    pub lease_duration: u64, // seconds a seat reserved with claimTask stays reserved, capped at the commit deadline
+    pub worker_stake: BigUint<M>, // EGLD each worker locks when submitting, returned if the hash wins, slashed to the creator otherwise
+    pub min_reputation: u64, // 0 means anyone can submit, otherwise only registered workers with at least this reputation
+    pub protocol_fee_bps: u64, // protocol fee at the time of posting, taken from the reward if consensus is reached
+    pub participation_bps: u64, // basis points of the reward split between every worker that revealed, whatever the outcome
+    pub parent_task_ids: ManagedVec<M, u64>, // tasks whose results this one consumes, the deadlines are 0 until they all completed
+    pub escalation: EscalationPolicy,
+    pub escalation_round: u32, // how many times the task was re-opened
+    pub private: bool, // only workers on the allow-list or in the worker group can take part, a private task never becomes public again
+    pub worker_group: ManagedBuffer<M>,
+    pub dispute_period: u64, // seconds of block timestamp the creator can dispute the result after consensus, 0 pays out right away
+    pub dispute_deadline: u64, // set when consensus is reached, moved to the end of the arbitration period when the result is disputed
 }
 
 
+// what the creator needs to fetch the output once consensus is reached
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug, PartialEq)]
+pub struct TaskResult<M: ManagedTypeApi>{
+    pub result_hash: ManagedBuffer<M>,
+    pub output_uris: ManagedVec<M, ManagedBuffer<M>>, // the locations given by the agreeing workers that sent one
+    pub agreeing_workers: ManagedVec<M, ManagedAddress<M>>,
+}
 
 //An empty contract. To be used as a template when starting a new contract from scratch.
 #[multiversx_sc::contract]
-pub trait DistributedComputing {
+pub trait DistributedComputing: admin::AdminModule + events::DistributedComputingEvents + workers::WorkersModule {
     #[init]
-    fn init(&self) {}
+    fn init(&self, treasury: ManagedAddress, protocol_fee_bps: u64) {
+        require!(
+            protocol_fee_bps <= admin::MAX_BPS, E_INVALID_PROTOCOL_FEE
+        );
+        self.treasury().set(treasury);
+        self.protocol_fee_bps().set(protocol_fee_bps);
+    }
 
     #[upgrade]
     fn upgrade(&self) {}
@@ -45,129 +188,1356 @@ pub trait DistributedComputing {
     //storages
 
 
-    // #[view(getTaskIdCounter)]
+    #[view(getTaskIdCounter)]
     #[storage_mapper("task_id_counter")]
     fn task_id_counter(&self) -> SingleValueMapper<u64>;
 
     #[storage_mapper("tasks")]
     fn tasks(&self, task_id: u64) -> SingleValueMapper<Task<Self::Api>>;
 
+    // seats reserved with claimTask, worker -> lease expiry (block timestamp), each lease holds the worker stake
+    #[storage_mapper("task_leases")]
+    fn task_leases(&self, task_id: u64) -> MapMapper<ManagedAddress, u64>;
+
+    // keccak256(keccak256(result_hash) || salt || worker address) sent by the worker in the commit phase
+    #[storage_mapper("worker_commitments")]
+    fn worker_commitments(&self, task_id: u64, worker: &ManagedAddress) -> SingleValueMapper<ManagedBuffer>;
+
+    // every commitment sent for the task, so the same one cant be committed twice
+    #[storage_mapper("task_commitments")]
+    fn task_commitments(&self, task_id: u64) -> WhitelistMapper<ManagedBuffer>;
+
+    // the revealed result hash
     #[storage_mapper("worker_submissions")]
     fn worker_submissions(&self, task_id: u64, worker: &ManagedAddress) -> SingleValueMapper<ManagedBuffer>;
 
+    // where the worker uploaded the output behind the revealed hash, optional
+    #[storage_mapper("worker_output_uris")]
+    fn worker_output_uris(&self, task_id: u64, worker: &ManagedAddress) -> SingleValueMapper<ManagedBuffer>;
+
+    // set when consensus is reached, cleared if the arbiter reverses the result
+    #[storage_mapper("task_results")]
+    fn task_results(&self, task_id: u64) -> SingleValueMapper<TaskResult<Self::Api>>;
+
+    // (duration, reveal_duration) of every task, used to set the deadlines again when a pending task opens or a task escalates
+    #[storage_mapper("task_schedule")]
+    fn task_schedule(&self, task_id: u64) -> SingleValueMapper<(u64, u64)>;
+
+    // what every address other than the creator added to the reward with increaseReward
+    #[storage_mapper("reward_contributions")]
+    fn reward_contributions(&self, task_id: u64) -> MapMapper<ManagedAddress, BigUint>;
+
+    // workers allowed on a private task, besides the ones in its worker group
+    #[storage_mapper("task_allow_list")]
+    fn task_allow_list(&self, task_id: u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    // the key to the task input, encrypted by the creator for one worker with the worker's encryption key
+    #[storage_mapper("encrypted_input_keys")]
+    fn encrypted_input_keys(&self, task_id: u64, worker: &ManagedAddress) -> SingleValueMapper<ManagedBuffer>;
+
+    // EGLD bond locked by the creator of a disputed task
+    #[storage_mapper("dispute_bonds")]
+    fn dispute_bonds(&self, task_id: u64) -> SingleValueMapper<BigUint>;
+
     #[storage_mapper("task_worker_list")]
     fn task_worker_list(&self, task_id: u64) -> VecMapper<ManagedAddress>;
 
     #[storage_mapper("hash_frequency")]
     fn hash_frequency(&self, task_id: u64, hash: &ManagedBuffer) -> SingleValueMapper<usize>;
 
+    // indexes used by the listing views, so clients dont have to scan every task id
+    #[storage_mapper("tasks_by_status")]
+    fn tasks_by_status(&self, status: &TaskStatus) -> UnorderedSetMapper<u64>;
 
-    // requester endpoints
-    #[payable("EGLD")]
-    #[endpoint(postTask)]
-    fn post_task(&self, docker_image_uri: ManagedBuffer, input_data_uri: ManagedBuffer, max_workers: usize){
-        let payment = self.call_value().egld();
-        require!(
-            *payment > 0 , "reward must be greater than 0"
-        );
+    #[storage_mapper("creator_tasks")]
+    fn creator_tasks(&self, creator: &ManagedAddress) -> VecMapper<u64>;
+
+    #[storage_mapper("worker_tasks")]
+    fn worker_tasks(&self, worker: &ManagedAddress) -> VecMapper<u64>;
+
+    // funds held on behalf of open tasks (rewards + worker stakes), per token
+    #[storage_mapper("escrowed_amount")]
+    fn escrowed_amount(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    // rewards, refunds and stakes credited at finalization, withdrawn with claimRewards
+    #[storage_mapper("claimable")]
+    fn claimable(&self, address: &ManagedAddress, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("claimable_tokens")]
+    fn claimable_tokens(&self, address: &ManagedAddress) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("total_claimable")]
+    fn total_claimable(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    // ESDTs that can be used as task rewards, EGLD is always accepted
+    #[view(getAcceptedTokens)]
+    #[storage_mapper("accepted_tokens")]
+    fn accepted_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
+
+
+    // owner endpoints
+    #[only_owner]
+    #[endpoint(addAcceptedToken)]
+    fn add_accepted_token(&self, token_id: TokenIdentifier){
         require!(
-            max_workers >= 1, "at least 3 workers for a consesus to be reached"
+            token_id.is_valid_esdt_identifier(), E_INVALID_TOKEN
         );
+        self.accepted_tokens().insert(token_id);
+    }
 
-        let task_id = self.task_id_counter().get();
-        let task = Task {
+    #[only_owner]
+    #[endpoint(removeAcceptedToken)]
+    fn remove_accepted_token(&self, token_id: TokenIdentifier){
+        // tasks already posted with this token are still paid out in it
+        self.accepted_tokens().swap_remove(&token_id);
+    }
+
+
+    // requester endpoints
+
+    // with parent tasks the new task stays Pending until activateTask is called after every parent completed
+    #[payable("*")]
+    #[endpoint(postTask)]
+    fn post_task(&self, spec: TaskSpec<Self::Api>, max_workers: usize, consensus_policy: ConsensusPolicy, duration: u64, reveal_duration: u64, lease_duration: u64, worker_stake: BigUint, min_reputation: u64, participation_bps: u64, escalation: EscalationPolicy, dispute_period: u64, access: TaskAccess<Self::Api>, parent_task_ids: MultiValueEncoded<u64>){
+        self.require_not_paused();
+
+        let (reward_token, reward_amount) = self.call_value().egld_or_single_fungible_esdt();
+        self.require_valid_reward_token(&reward_token);
+        self.require_valid_terms(duration, reveal_duration, lease_duration, participation_bps);
+
+        let commit_deadline = self.blockchain().get_block_timestamp().saturating_add(duration);
+        let task_id = self.create_task(Task {
             creator: self.blockchain().get_caller(),
-            docker_image_uri,
-            input_data_uri,
-            reward_amount: payment.clone_value(),
+            spec,
+            reward_token,
+            reward_amount,
             max_workers,
+            consensus_policy,
             submissions_count: 0,
+            revealed_count: 0,
             status: TaskStatus::Open,
-        };
+            commit_deadline,
+            deadline: commit_deadline.saturating_add(reveal_duration),
+            lease_duration,
+            worker_stake,
+            min_reputation,
+            protocol_fee_bps: self.protocol_fee_bps().get(),
+            participation_bps,
+            parent_task_ids: parent_task_ids.to_vec(),
+            escalation,
+            escalation_round: 0,
+            private: !access.allow_list.is_empty() || !access.worker_group.is_empty(),
+            worker_group: access.worker_group,
+            dispute_period,
+            dispute_deadline: 0,
+        });
+        self.task_allow_list(task_id).extend(access.allow_list.iter().map(|worker| worker.clone()));
+    }
 
-        self.tasks(task_id).set(&task);
-        self.task_id_counter().update(|id| *id +=1)
+    // posts one task per (spec, max_workers, reward_share) entry out of a single payment, the other settings are shared
+    // the shares have to add up to the payment, returns the first and last task id created
+    #[payable("*")]
+    #[endpoint(postTasks)]
+    fn post_tasks(&self, consensus_policy: ConsensusPolicy, duration: u64, reveal_duration: u64, lease_duration: u64, worker_stake: BigUint, min_reputation: u64, participation_bps: u64, escalation: EscalationPolicy, dispute_period: u64, access: TaskAccess<Self::Api>, entries: MultiValueEncoded<MultiValue3<TaskSpec<Self::Api>, usize, BigUint>>) -> MultiValue2<u64, u64> {
+        self.require_not_paused();
+
+        let (reward_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        self.require_valid_reward_token(&reward_token);
+        self.require_valid_terms(duration, reveal_duration, lease_duration, participation_bps);
+        require!(
+            !entries.is_empty(), E_EMPTY_BATCH
+        );
+
+        let creator = self.blockchain().get_caller();
+        let protocol_fee_bps = self.protocol_fee_bps().get();
+        let commit_deadline = self.blockchain().get_block_timestamp().saturating_add(duration);
+        let first_task_id = self.task_id_counter().get();
+        let mut total_shares = BigUint::zero();
+        for entry in entries.into_iter() {
+            let (spec, max_workers, reward_amount) = entry.into_tuple();
+            total_shares += &reward_amount;
+            let task_id = self.create_task(Task {
+                creator: creator.clone(),
+                spec,
+                reward_token: reward_token.clone(),
+                reward_amount,
+                max_workers,
+                consensus_policy: consensus_policy.clone(),
+                submissions_count: 0,
+                revealed_count: 0,
+                status: TaskStatus::Open,
+                commit_deadline,
+                deadline: commit_deadline.saturating_add(reveal_duration),
+                lease_duration,
+                worker_stake: worker_stake.clone(),
+                min_reputation,
+                protocol_fee_bps,
+                participation_bps,
+                parent_task_ids: ManagedVec::new(),
+                escalation: escalation.clone(),
+                escalation_round: 0,
+                private: !access.allow_list.is_empty() || !access.worker_group.is_empty(),
+                worker_group: access.worker_group.clone(),
+                dispute_period,
+                dispute_deadline: 0,
+            });
+            self.task_allow_list(task_id).extend(access.allow_list.iter().map(|worker| worker.clone()));
+        }
+        require!(
+            total_shares == payment_amount, E_SHARES_MISMATCH
+        );
+
+        (first_task_id, self.task_id_counter().get() - 1).into()
     }
 
 
-    // worker endpoint
+    // the creator can withdraw a task posted by mistake while it is still open (or pending)
+    // workers that already submitted keep their stake and are compensated with one seat worth of the reward (reward / max_workers)
+    #[endpoint(cancelTask)]
+    fn cancel_task(&self, task_id: u64){
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
+        let mut task = self.tasks(task_id).get();
 
-    #[endpoint(submitResult)]
-    fn submit_result(&self, task_id: u64, result_hash: ManagedBuffer){
+        require!(
+            self.blockchain().get_caller() == task.creator, E_NOT_TASK_CREATOR
+        );
+        require!(
+            task.status == TaskStatus::Open || task.status == TaskStatus::Pending, E_TASK_NOT_OPEN
+        );
+
+        let compensation = &task.reward_amount / (task.max_workers as u64);
+        let mut refund_amount = task.reward_amount.clone();
+        for worker in self.task_worker_list(task_id).iter(){
+            self.credit_reward(&task, &worker, &compensation);
+            self.return_stake(&worker, &task.worker_stake);
+            self.emit_reward_paid_event(task_id, &worker, &compensation);
+            refund_amount -= &compensation;
+        }
+
+        self.refund_contributors(&task, task_id, &refund_amount);
+
+        self.change_status(task_id, &mut task, TaskStatus::Cancelled);
+        self.tasks(task_id).set(&task);
+        self.compact_task(task_id, &task);
+
+        self.emit_task_cancelled_event(task_id, &task.creator, &refund_amount);
+    }
+
+
+    // anyone can add to the reward of a task that is still waiting for workers, in the token it was funded with
+    // if the task fails or is cancelled the refund is split between the creator and the sponsors pro rata
+    #[payable("*")]
+    #[endpoint(increaseReward)]
+    fn increase_reward(&self, task_id: u64){
+        self.require_not_paused();
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
         let mut task = self.tasks(task_id).get();
-        let caller = self.blockchain().get_caller();
 
         require!(
-            task.status == TaskStatus::Open, "task is not open!!!"
+            task.status == TaskStatus::Open || task.status == TaskStatus::Pending, E_TASK_NOT_OPEN
         );
 
+        let (token, amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(
+            token == task.reward_token, E_WRONG_PAYMENT_TOKEN
+        );
         require!(
-            self.worker_submissions(task_id, &caller).is_empty(), "worker alrdy submitted"
+            amount > 0, E_ZERO_REWARD
         );
 
-        self.worker_submissions(task_id, &caller).set(&result_hash);
-        self.task_worker_list(task_id).push(&caller);
+        let sponsor = self.blockchain().get_caller();
+        if sponsor != task.creator {
+            let mut contributions = self.reward_contributions(task_id);
+            let contributed = contributions.get(&sponsor).unwrap_or_default();
+            require!(
+                contributed > 0 || contributions.len() < MAX_SPONSORS, E_TOO_MANY_SPONSORS
+            );
+            contributions.insert(sponsor.clone(), contributed + &amount);
+        }
 
-        self.hash_frequency(task_id, &result_hash).update(|count| * count += 1);
-        task.submissions_count += 1;
+        self.escrowed_amount(&task.reward_token).update(|escrowed| *escrowed += &amount);
+        task.reward_amount += &amount;
+        self.tasks(task_id).set(&task);
 
-        if task.submissions_count == task.max_workers {
-            task.status = TaskStatus::InVerification;
-            self.finalize_task(task_id, &mut task);
+        self.emit_reward_increased_event(task_id, &sponsor, &amount, &task.reward_amount);
+    }
+
+
+    // the creator can edit who is allowed while the task is open, workers that already committed or hold a lease keep their seat
+    // adding workers or setting a group makes a public task private
+    #[endpoint(addToAllowList)]
+    fn add_to_allow_list(&self, task_id: u64, workers: MultiValueEncoded<ManagedAddress>){
+        let mut task = self.require_editable_task(task_id);
+        self.task_allow_list(task_id).extend(workers);
+        task.private = true;
+        self.tasks(task_id).set(&task);
+    }
+
+    #[endpoint(removeFromAllowList)]
+    fn remove_from_allow_list(&self, task_id: u64, workers: MultiValueEncoded<ManagedAddress>){
+        self.require_editable_task(task_id);
+        let mut allow_list = self.task_allow_list(task_id);
+        for worker in workers {
+            allow_list.swap_remove(&worker);
         }
+    }
 
+    // an empty name removes the group, the task stays private
+    #[endpoint(setTaskWorkerGroup)]
+    fn set_task_worker_group(&self, task_id: u64, worker_group: ManagedBuffer){
+        let mut task = self.require_editable_task(task_id);
+        require!(
+            worker_group.len() <= workers::MAX_GROUP_NAME_LENGTH, E_INVALID_GROUP_NAME
+        );
+        task.private = task.private || !worker_group.is_empty();
+        task.worker_group = worker_group;
         self.tasks(task_id).set(&task);
     }
 
+    // hands the input key to the workers that hold a seat (an active lease or a commitment), the contract only stores the ciphertexts
+    #[endpoint(attachInputKeys)]
+    fn attach_input_keys(&self, task_id: u64, keys: MultiValueEncoded<MultiValue2<ManagedAddress, ManagedBuffer>>){
+        let task = self.require_editable_task(task_id);
+        require!(
+            task.status == TaskStatus::Open, E_TASK_NOT_OPEN
+        );
 
-    // logic functions
+        let now = self.blockchain().get_block_timestamp();
+        let leases = self.task_leases(task_id);
+        for entry in keys {
+            let (worker, encrypted_key) = entry.into_tuple();
+            let has_seat = leases.get(&worker).is_some_and(|lease_expiry| lease_expiry > now)
+                || !self.worker_commitments(task_id, &worker).is_empty();
+            require!(
+                has_seat, E_WORKER_NOT_ASSIGNED
+            );
+            require!(
+                !self.worker_encryption_keys(&worker).is_empty(), E_NO_ENCRYPTION_KEY
+            );
+            require!(
+                !encrypted_key.is_empty() && encrypted_key.len() <= MAX_ENCRYPTED_KEY_LENGTH, E_INVALID_ENCRYPTED_KEY
+            );
 
-    fn finalize_task(&self, task_id: u64, task: &mut Task<Self::Api>){
-        let mut winning_hash = ManagedBuffer::new();
-        let mut max_votes = 0usize;
-        let majority_threshhold = (task.max_workers / 2) + 1;
+            self.encrypted_input_keys(task_id, &worker).set(&encrypted_key);
+            self.emit_input_key_attached_event(task_id, &worker);
+        }
+    }
 
-        for worker in self.task_worker_list(task_id).iter(){
-            let hash = self.worker_submissions(task_id, &worker).get();
-            let votes = self.hash_frequency(task_id, &hash).get();
 
-            if votes >= majority_threshhold {
-                winning_hash = hash;
-                max_votes = votes;
-                break;
-            }
+    // the creator can challenge the result of a task during its dispute period, the payouts wait for the arbiter
+    #[payable("EGLD")]
+    #[endpoint(disputeResult)]
+    fn dispute_result(&self, task_id: u64){
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
+        let mut task = self.tasks(task_id).get();
+
+        require!(
+            self.blockchain().get_caller() == task.creator, E_NOT_TASK_CREATOR
+        );
+        require!(
+            task.status == TaskStatus::Verified, E_TASK_NOT_VERIFIED
+        );
+        require!(
+            self.blockchain().get_block_timestamp() < task.dispute_deadline, E_DISPUTE_PERIOD_ENDED
+        );
+        require!(
+            !self.arbiter().is_empty(), E_NO_ARBITER
+        );
+
+        let bond = self.call_value().egld().clone_value();
+        require!(
+            bond == self.dispute_bond().get(), E_WRONG_DISPUTE_BOND
+        );
+
+        self.escrowed_amount(&EgldOrEsdtTokenIdentifier::egld()).update(|amount| *amount += &bond);
+        self.dispute_bonds(task_id).set(&bond);
+        task.dispute_deadline = self.blockchain().get_block_timestamp().saturating_add(ARBITRATION_PERIOD);
+        self.change_status(task_id, &mut task, TaskStatus::Disputed);
+        self.tasks(task_id).set(&task);
+
+        self.emit_dispute_opened_event(task_id, &task.creator, &bond);
+    }
+
+    // the arbiter either confirms the result, the payouts go ahead and the bond goes to the treasury,
+    // or reverses it, the reward is refunded, the agreeing workers are slashed and the creator gets the bond back
+    #[endpoint(resolveDispute)]
+    fn resolve_dispute(&self, task_id: u64, reverse_result: bool){
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
+        let mut task = self.tasks(task_id).get();
+        let arbiter = self.blockchain().get_caller();
+
+        require!(
+            !self.arbiter().is_empty() && arbiter == self.arbiter().get(), E_NOT_ARBITER
+        );
+        require!(
+            task.status == TaskStatus::Disputed, E_TASK_NOT_DISPUTED
+        );
+
+        let bond = self.dispute_bonds(task_id).take();
+        let egld = EgldOrEsdtTokenIdentifier::egld();
+        if reverse_result {
+            self.credit(&task.creator, &egld, &bond);
+            self.reverse_result(task_id, &mut task);
+        } else {
+            self.credit(&self.treasury().get(), &egld, &bond);
+            self.settle_result(task_id, &mut task);
         }
+        self.tasks(task_id).set(&task);
 
-        if max_votes >= majority_threshhold {
-            self.distribute_rewards(task_id, task, &winning_hash, max_votes);
-            task.status = TaskStatus::Completed;
-        } else { // NO consensus
-            self.send().direct_egld(&task.creator, &task.reward_amount);
-            task.status = TaskStatus::Failed;
+        self.emit_dispute_resolved_event(task_id, &arbiter, reverse_result);
+    }
+
+    // anyone can release the payouts once the dispute period ended without a dispute, or once the arbiter
+    // let the arbitration period of a dispute pass: the result stands and the creator gets the bond back,
+    // so an absent arbiter cant keep the reward and the stakes locked
+    #[endpoint(settleTask)]
+    fn settle_task(&self, task_id: u64){
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
+        let mut task = self.tasks(task_id).get();
+        let now = self.blockchain().get_block_timestamp();
+
+        if task.status == TaskStatus::Disputed {
+            require!(
+                now >= task.dispute_deadline, E_ARBITRATION_PERIOD_ACTIVE
+            );
+            let bond = self.dispute_bonds(task_id).take();
+            self.credit(&task.creator, &EgldOrEsdtTokenIdentifier::egld(), &bond);
+            self.emit_dispute_timed_out_event(task_id, &task.creator, &bond);
+        } else {
+            require!(
+                task.status == TaskStatus::Verified, E_TASK_NOT_VERIFIED
+            );
+            require!(
+                now >= task.dispute_deadline, E_DISPUTE_PERIOD_ACTIVE
+            );
         }
 
+        self.settle_result(task_id, &mut task);
+        self.tasks(task_id).set(&task);
     }
 
-    fn distribute_rewards(&self, task_id: u64, task: &Task<Self::Api>, winning_hash: &ManagedBuffer, winner_count: usize){
-        let share = &task.reward_amount / (winner_count as u64);
-        for worker in self.task_worker_list(task_id).iter(){
-            let hash = self.worker_submissions(task_id, &worker).get();
-            if &hash == winning_hash {
-                self.send().direct_egld(&worker, &share);
+
+    // anyone can move a pending task on once its parents are done: it opens if they all completed,
+    // it fails and refunds if one of them did not. parents never touch their dependents, so whoever
+    // lists a task as a parent cant make its finalization any more expensive
+    #[endpoint(activateTask)]
+    fn activate_task(&self, task_id: u64){
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
+        let mut task = self.tasks(task_id).get();
+        require!(
+            task.status == TaskStatus::Pending, E_TASK_NOT_PENDING
+        );
+
+        let mut all_parents_completed = true;
+        let mut parent_failed = false;
+        for parent_id in task.parent_task_ids.iter() {
+            match self.tasks(parent_id).get().status {
+                TaskStatus::Completed => {},
+                TaskStatus::Failed | TaskStatus::Expired | TaskStatus::Cancelled => parent_failed = true,
+                _ => all_parents_completed = false,
             }
         }
+
+        if parent_failed {
+            self.refund_contributors(&task, task_id, &task.reward_amount);
+            self.change_status(task_id, &mut task, TaskStatus::Failed);
+            self.tasks(task_id).set(&task);
+            self.compact_task(task_id, &task);
+            self.emit_task_failed_event(task_id, &task.creator, &task.reward_amount);
+            return;
+        }
+
+        require!(
+            all_parents_completed, E_PARENTS_NOT_FINISHED
+        );
+        let (duration, reveal_duration) = self.task_schedule(task_id).get();
+        task.commit_deadline = self.blockchain().get_block_timestamp().saturating_add(duration);
+        task.deadline = task.commit_deadline.saturating_add(reveal_duration);
+        self.change_status(task_id, &mut task, TaskStatus::Open);
+        self.tasks(task_id).set(&task);
+
+        self.emit_task_activated_event(task_id, task.commit_deadline, task.deadline);
     }
 
 
-    #[view(getTask)]
-    fn get_task(&self, task_id: u64) -> Task<Self::Api> {
-        self.tasks(task_id).get()
+    // anyone can clear the per-worker storage of a finished task, PRUNE_BATCH_SIZE workers per call
+    // returns how many workers are left, call again until it returns 0
+    #[endpoint(pruneTask)]
+    fn prune_task(&self, task_id: u64) -> usize {
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
+        let status = self.tasks(task_id).get().status;
+        require!(
+            matches!(status, TaskStatus::Completed | TaskStatus::Failed | TaskStatus::Expired | TaskStatus::Cancelled),
+            E_TASK_NOT_FINISHED
+        );
+
+        let mut workers = self.task_worker_list(task_id);
+        for _ in 0..core::cmp::min(PRUNE_BATCH_SIZE, workers.len()) {
+            let last = workers.len();
+            let worker = workers.get(last);
+            workers.swap_remove(last);
+
+            self.task_commitments(task_id).remove(&self.worker_commitments(task_id, &worker).take());
+            self.worker_submissions(task_id, &worker).clear();
+            self.worker_output_uris(task_id, &worker).clear();
+            self.encrypted_input_keys(task_id, &worker).clear();
+        }
+
+        if workers.is_empty() {
+            self.task_allow_list(task_id).clear();
+            self.reward_contributions(task_id).clear();
+            self.emit_task_pruned_event(task_id);
+        }
+        workers.len()
     }
 
-    #[view(getTaskStatus)]
-    fn get_task_status(&self, task_id: u64) -> TaskStatus {
+
+    // worker endpoints
+
+    // reserves a seat before starting the computation, so the worker knows the commit will not be rejected for lack of seats
+    // the worker stake is locked with the claim and carried over to the commit, it is forfeited to the creator if the lease runs out
+    // returns the lease expiry, after which the seat is given to someone else if the worker did not commit
+    #[payable("EGLD")]
+    #[endpoint(claimTask)]
+    fn claim_task(&self, task_id: u64) -> u64 {
+        self.require_not_paused();
+
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
         let task = self.tasks(task_id).get();
-        task.status
+        let caller = self.blockchain().get_caller();
+        let now = self.blockchain().get_block_timestamp();
+
+        require!(
+            task.status == TaskStatus::Open, E_TASK_NOT_OPEN
+        );
+
+        require!(
+            now < task.commit_deadline, E_COMMIT_PHASE_ENDED
+        );
+
+        require!(
+            self.worker_commitments(task_id, &caller).is_empty(), E_DUPLICATE_SUBMISSION
+        );
+
+        require!(
+            self.is_worker_eligible(&caller, task.min_reputation), E_REPUTATION_TOO_LOW
+        );
+
+        require!(
+            self.is_worker_allowed(task_id, &task, &caller), E_WORKER_NOT_ALLOWED
+        );
+
+        let active_leases = self.release_expired_leases(task_id, &task);
+        require!(
+            !self.task_leases(task_id).contains_key(&caller), E_LEASE_ALREADY_HELD
+        );
+        require!(
+            task.submissions_count + active_leases < task.max_workers, E_NO_FREE_SEATS
+        );
+
+        require!(
+            *self.call_value().egld() == task.worker_stake, E_WRONG_STAKE
+        );
+        self.escrowed_amount(&EgldOrEsdtTokenIdentifier::egld()).update(|amount| *amount += &task.worker_stake);
+
+        let lease_expiry = core::cmp::min(now.saturating_add(task.lease_duration), task.commit_deadline);
+        self.task_leases(task_id).insert(caller.clone(), lease_expiry);
+
+        self.emit_task_claimed_event(task_id, &caller, lease_expiry);
+
+        lease_expiry
+    }
+
+    // commit phase: the worker only sends keccak256(keccak256(result_hash) || salt || worker address) so nobody can copy the leading hash,
+    // the address in the preimage keeps a copied commitment from being revealed by someone else and hashing the result first
+    // gives it a fixed length, so the same bytes cant be revealed as a different result_hash and salt split
+    #[payable("EGLD")]
+    #[endpoint(submitResult)]
+    fn submit_result(&self, task_id: u64, commitment: ManagedBuffer){
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
+        let mut task = self.tasks(task_id).get();
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            task.status == TaskStatus::Open, E_TASK_NOT_OPEN
+        );
+
+        require!(
+            self.blockchain().get_block_timestamp() < task.commit_deadline, E_COMMIT_PHASE_ENDED
+        );
+
+        require!(
+            self.worker_commitments(task_id, &caller).is_empty(), E_DUPLICATE_SUBMISSION
+        );
+
+        // a worker holding a lease uses its own seat, everyone else needs a seat nobody reserved
+        let active_leases = self.release_expired_leases(task_id, &task);
+        let holds_lease = self.task_leases(task_id).remove(&caller).is_some();
+
+        // a lease was only granted after these checks, editing the access afterwards cant take the seat and its stake away
+        // and a pause cant make it run out
+        if !holds_lease {
+            self.require_not_paused();
+
+            require!(
+                self.is_worker_eligible(&caller, task.min_reputation), E_REPUTATION_TOO_LOW
+            );
+
+            require!(
+                self.is_worker_allowed(task_id, &task, &caller), E_WORKER_NOT_ALLOWED
+            );
+        }
+
+        require!(
+            holds_lease || task.submissions_count + active_leases < task.max_workers, E_NO_FREE_SEATS
+        );
+
+        require!(
+            !self.task_commitments(task_id).contains(&commitment), E_DUPLICATE_COMMITMENT
+        );
+
+        // the lease already holds the stake
+        if holds_lease {
+            require!(
+                *self.call_value().egld() == 0, E_WRONG_STAKE
+            );
+        } else {
+            require!(
+                *self.call_value().egld() == task.worker_stake, E_WRONG_STAKE
+            );
+            self.escrowed_amount(&EgldOrEsdtTokenIdentifier::egld()).update(|amount| *amount += &task.worker_stake);
+        }
+
+        self.task_commitments(task_id).add(&commitment);
+        self.worker_commitments(task_id, &caller).set(&commitment);
+        self.task_worker_list(task_id).push(&caller);
+        self.worker_tasks(&caller).push(&task_id);
+
+        task.submissions_count += 1;
+        self.tasks(task_id).set(&task);
+
+        self.emit_result_committed_event(task_id, &caller, &commitment);
+    }
+
+    // reveal phase: opens once the commit deadline passed or every seat has a commitment
+    // output_uri is the content-addressed location of the output, it can be left empty
+    #[endpoint(revealResult)]
+    fn reveal_result(&self, task_id: u64, result_hash: ManagedBuffer, salt: ManagedBuffer, output_uri: ManagedBuffer){
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
+        let mut task = self.tasks(task_id).get();
+        let caller = self.blockchain().get_caller();
+        let now = self.blockchain().get_block_timestamp();
+
+        require!(
+            task.status == TaskStatus::Open, E_TASK_NOT_OPEN
+        );
+
+        require!(
+            now >= task.commit_deadline || task.submissions_count == task.max_workers, E_REVEAL_NOT_STARTED
+        );
+
+        require!(
+            now < task.deadline, E_DEADLINE_PASSED
+        );
+
+        let commitment_mapper = self.worker_commitments(task_id, &caller);
+        require!(
+            !commitment_mapper.is_empty(), E_NO_COMMITMENT
+        );
+
+        require!(
+            self.worker_submissions(task_id, &caller).is_empty(), E_DUPLICATE_REVEAL
+        );
+
+        require!(
+            !result_hash.is_empty(), E_EMPTY_RESULT_HASH
+        );
+
+        require!(
+            output_uri.len() <= MAX_URI_LENGTH, E_INVALID_OUTPUT_URI
+        );
+
+        let mut preimage = self.crypto().keccak256(&result_hash).as_managed_buffer().clone();
+        preimage.append(&salt);
+        preimage.append(caller.as_managed_buffer());
+        require!(
+            self.crypto().keccak256(&preimage).as_managed_buffer() == &commitment_mapper.get(), E_COMMITMENT_MISMATCH
+        );
+
+        self.worker_submissions(task_id, &caller).set(&result_hash);
+        if !output_uri.is_empty() {
+            self.worker_output_uris(task_id, &caller).set(&output_uri);
+        }
+        self.hash_frequency(task_id, &result_hash).update(|count| * count += 1);
+        task.revealed_count += 1;
+
+        self.emit_result_submitted_event(task_id, &caller, &result_hash);
+
+        if task.revealed_count == task.max_workers {
+            self.change_status(task_id, &mut task, TaskStatus::InVerification);
+            self.emit_verification_started_event(task_id, task.revealed_count);
+            self.finalize_task(task_id, &mut task);
+        }
+
+        self.tasks(task_id).set(&task);
+    }
+
+
+    // anyone can call this once the deadline passed, so the creator's funds dont stay locked if not enough workers show up
+    #[endpoint(expireTask)]
+    fn expire_task(&self, task_id: u64){
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
+        let mut task = self.tasks(task_id).get();
+
+        require!(
+            task.status == TaskStatus::Open, E_TASK_NOT_OPEN
+        );
+        require!(
+            self.blockchain().get_block_timestamp() >= task.deadline, E_DEADLINE_NOT_PASSED
+        );
+
+        // run the consensus over the votes revealed so far, no votes at all means a plain refund
+        self.change_status(task_id, &mut task, TaskStatus::InVerification);
+        self.emit_verification_started_event(task_id, task.revealed_count);
+        self.finalize_task(task_id, &mut task);
+        if task.status == TaskStatus::Failed {
+            self.change_status(task_id, &mut task, TaskStatus::Expired);
+            self.emit_task_expired_event(task_id, task.submissions_count);
+        }
+
+        self.tasks(task_id).set(&task);
+    }
+
+
+    // withdraws everything credited to the caller, in every token
+    #[endpoint(claimRewards)]
+    fn claim_rewards(&self){
+        let caller = self.blockchain().get_caller();
+        let mut tokens = self.claimable_tokens(&caller);
+        require!(
+            !tokens.is_empty(), E_NOTHING_TO_CLAIM
+        );
+
+        for token in tokens.iter() {
+            let amount = self.claimable(&caller, &token).take();
+            self.total_claimable(&token).update(|total| *total -= &amount);
+            self.send().direct(&caller, &token, 0, &amount);
+
+            self.emit_rewards_claimed_event(&caller, &token, &amount);
+        }
+        tokens.clear();
+    }
+
+
+    // logic functions
+
+    fn finalize_task(&self, task_id: u64, task: &mut Task<Self::Api>){
+        let mut winning_hash = ManagedBuffer::new();
+        let mut max_votes = 0usize;
+        let majority_threshhold = task.consensus_policy.required_votes(task.max_workers);
+
+        for worker in self.task_worker_list(task_id).iter(){
+            let hash = self.worker_submissions(task_id, &worker).get();
+            let votes = self.hash_frequency(task_id, &hash).get();
+
+            if votes >= majority_threshhold {
+                winning_hash = hash;
+                max_votes = votes;
+                break;
+            }
+        }
+
+        if max_votes >= majority_threshhold {
+            self.emit_consensus_reached_event(task_id, &winning_hash, max_votes);
+            self.record_result(task_id, &winning_hash);
+            if task.dispute_period == 0 {
+                self.settle_result(task_id, task);
+            } else {
+                task.dispute_deadline = self.blockchain().get_block_timestamp().saturating_add(task.dispute_period);
+                self.change_status(task_id, task, TaskStatus::Verified);
+            }
+        } else if task.revealed_count > 0 && task.escalation_round < task.escalation.max_rounds {
+            self.escalate(task_id, task);
+        } else { // NO consensus
+            // workers that revealed still get the participation share, the rest goes back to the creator
+            let participation_share = self.participation_share(task, &task.reward_amount);
+            let refund_amount = &task.reward_amount - &(&participation_share * (task.revealed_count as u64));
+            self.refund_contributors(task, task_id, &refund_amount);
+            self.emit_reward_split_event(task_id, &participation_share, &BigUint::zero());
+            self.emit_task_failed_event(task_id, &task.creator, &refund_amount);
+            // nobody can be proven wrong without a majority, so every revealed stake goes back
+            // workers that never revealed forfeit their stake to the creator
+            let mut forfeited = BigUint::zero();
+            for worker in self.task_worker_list(task_id).iter(){
+                if self.worker_submissions(task_id, &worker).is_empty() {
+                    forfeited += &task.worker_stake;
+                    self.update_worker_stats(&worker, &task.creator, |stats| stats.expired_commitments += 1);
+                } else {
+                    self.credit_reward(task, &worker, &participation_share);
+                    self.return_stake(&worker, &task.worker_stake);
+                    if participation_share > 0 {
+                        self.emit_reward_paid_event(task_id, &worker, &participation_share);
+                    }
+                    self.update_worker_stats(&worker, &task.creator, |_| {});
+                }
+            }
+            self.credit(&task.creator, &EgldOrEsdtTokenIdentifier::egld(), &forfeited);
+            self.change_status(task_id, task, TaskStatus::Failed);
+        }
+
+        if task.status != TaskStatus::Open {
+            self.compact_task(task_id, task);
+        }
+    }
+
+    // drops what was only needed to reach consensus, the outcome stays in the task status and the TaskResult
+    // the per-worker entries are left for pruneTask, their number is only bounded by max_workers
+    fn compact_task(&self, task_id: u64, task: &Task<Self::Api>){
+        for worker in self.task_worker_list(task_id).iter(){
+            let hash = self.worker_submissions(task_id, &worker).get();
+            if !hash.is_empty() {
+                self.hash_frequency(task_id, &hash).clear();
+            }
+        }
+        // leases can only still be active when the task was cancelled, these workers get their stake back
+        // pruneTask only walks the committed workers, so the input keys of lease holders are dropped here
+        self.release_expired_leases(task_id, task);
+        for (worker, _) in self.task_leases(task_id).iter() {
+            self.return_stake(&worker, &task.worker_stake);
+            self.encrypted_input_keys(task_id, &worker).clear();
+        }
+        self.task_leases(task_id).clear();
+        self.task_schedule(task_id).clear();
+    }
+
+    fn record_result(&self, task_id: u64, winning_hash: &ManagedBuffer){
+        let mut result = TaskResult {
+            result_hash: winning_hash.clone(),
+            output_uris: ManagedVec::new(),
+            agreeing_workers: ManagedVec::new(),
+        };
+        for worker in self.task_worker_list(task_id).iter(){
+            if &self.worker_submissions(task_id, &worker).get() == winning_hash {
+                let output_uri_mapper = self.worker_output_uris(task_id, &worker);
+                if !output_uri_mapper.is_empty() {
+                    result.output_uris.push(output_uri_mapper.get());
+                }
+                result.agreeing_workers.push(worker);
+            }
+        }
+        self.task_results(task_id).set(&result);
+    }
+
+    fn settle_result(&self, task_id: u64, task: &mut Task<Self::Api>){
+        let result = self.task_results(task_id).get();
+        self.distribute_rewards(task_id, task, &result.result_hash, result.agreeing_workers.len());
+        self.change_status(task_id, task, TaskStatus::Completed);
+    }
+
+    // the arbiter found the result wrong: like a failed consensus every revealed worker keeps the participation
+    // share and the creator and sponsors get the rest of the reward back, the agreeing workers and the ones
+    // that never revealed lose their stake, the other revealed workers get theirs back
+    fn reverse_result(&self, task_id: u64, task: &mut Task<Self::Api>){
+        let result = self.task_results(task_id).take();
+        let participation_share = self.participation_share(task, &task.reward_amount);
+        let refund_amount = &task.reward_amount - &(&participation_share * (task.revealed_count as u64));
+        self.refund_contributors(task, task_id, &refund_amount);
+        self.emit_reward_split_event(task_id, &participation_share, &BigUint::zero());
+
+        let mut slashed = BigUint::zero();
+        for worker in self.task_worker_list(task_id).iter(){
+            let hash = self.worker_submissions(task_id, &worker).get();
+            if hash.is_empty() {
+                slashed += &task.worker_stake;
+                self.update_worker_stats(&worker, &task.creator, |stats| stats.expired_commitments += 1);
+                continue;
+            }
+            self.credit_reward(task, &worker, &participation_share);
+            if participation_share > 0 {
+                self.emit_reward_paid_event(task_id, &worker, &participation_share);
+            }
+            if hash == result.result_hash {
+                slashed += &task.worker_stake;
+                self.update_worker_stats(&worker, &task.creator, |stats| stats.losses += 1);
+            } else {
+                self.return_stake(&worker, &task.worker_stake);
+                self.update_worker_stats(&worker, &task.creator, |_| {});
+            }
+        }
+        self.credit(&task.creator, &EgldOrEsdtTokenIdentifier::egld(), &slashed);
+
+        self.emit_task_failed_event(task_id, &task.creator, &refund_amount);
+        self.change_status(task_id, task, TaskStatus::Failed);
+    }
+
+    // re-opens the task for more workers, the votes revealed so far stay and count in the next consensus
+    fn escalate(&self, task_id: u64, task: &mut Task<Self::Api>){
+        let (duration, reveal_duration) = self.task_schedule(task_id).get();
+        task.escalation_round += 1;
+        task.max_workers += task.escalation.extra_workers;
+        task.commit_deadline = self.blockchain().get_block_timestamp().saturating_add(duration);
+        task.deadline = task.commit_deadline.saturating_add(reveal_duration);
+        self.change_status(task_id, task, TaskStatus::Open);
+
+        self.emit_task_escalated_event(task_id, task.escalation_round, task.max_workers, task.deadline);
+    }
+
+    // the part of the escalation reserve set aside for the rounds that didnt happen
+    fn unused_reserve(&self, task: &Task<Self::Api>) -> BigUint {
+        if task.escalation.max_rounds == 0 {
+            return BigUint::zero();
+        }
+        let reserve = &task.reward_amount * task.escalation.reserve_bps / admin::MAX_BPS;
+        reserve * (task.escalation.max_rounds - task.escalation_round) / task.escalation.max_rounds
+    }
+
+    fn distribute_rewards(&self, task_id: u64, task: &Task<Self::Api>, winning_hash: &ManagedBuffer, winner_count: usize){
+        let unused_reserve = self.unused_reserve(task);
+        self.refund_contributors(task, task_id, &unused_reserve);
+
+        let spent = &task.reward_amount - &unused_reserve;
+        let protocol_fee = &spent * task.protocol_fee_bps / admin::MAX_BPS;
+        let distributable = &spent - &protocol_fee;
+        if protocol_fee > 0 {
+            let treasury = self.treasury().get();
+            self.credit_reward(task, &treasury, &protocol_fee);
+            self.emit_protocol_fee_paid_event(task_id, &treasury, &protocol_fee);
+        }
+
+        // every revealed worker gets the participation share, the winners split what is left
+        let participation_share = self.participation_share(task, &distributable);
+        let winners_pool = &distributable - &(&participation_share * (task.revealed_count as u64));
+        let share = &winners_pool / (winner_count as u64);
+        // whatever doesnt divide evenly goes back to the creator instead of staying in the contract
+        let remainder = &winners_pool - &(&share * (winner_count as u64));
+        self.emit_reward_split_event(task_id, &participation_share, &share);
+
+        let winner_payout = &share + &participation_share;
+        let mut slashed = BigUint::zero();
+        for worker in self.task_worker_list(task_id).iter(){
+            let hash = self.worker_submissions(task_id, &worker).get();
+            if &hash == winning_hash {
+                self.credit_reward(task, &worker, &winner_payout);
+                self.return_stake(&worker, &task.worker_stake);
+                self.emit_reward_paid_event(task_id, &worker, &winner_payout);
+                self.update_worker_stats(&worker, &task.creator, |stats| stats.wins += 1);
+            } else if hash.is_empty() {
+                slashed += &task.worker_stake;
+                self.update_worker_stats(&worker, &task.creator, |stats| stats.expired_commitments += 1);
+            } else {
+                self.credit_reward(task, &worker, &participation_share);
+                if participation_share > 0 {
+                    self.emit_reward_paid_event(task_id, &worker, &participation_share);
+                }
+                slashed += &task.worker_stake;
+                self.update_worker_stats(&worker, &task.creator, |stats| stats.losses += 1);
+            }
+        }
+
+        self.credit_reward(task, &task.creator, &remainder);
+
+        // minority and unrevealed stakes go to the creator, they paid for a result and got a wrong one (or none) from these workers
+        self.credit(&task.creator, &EgldOrEsdtTokenIdentifier::egld(), &slashed);
+    }
+
+    // validates and stores a new task, the reward has to be paid already
+    fn create_task(&self, mut task: Task<Self::Api>) -> u64 {
+        require!(
+            task.reward_amount > 0 , E_ZERO_REWARD
+        );
+        self.require_valid_spec(&task.spec);
+        require!(
+            task.max_workers >= 1, E_NO_WORKERS
+        );
+        require!(
+            task.max_workers <= MAX_WORKERS, E_TOO_MANY_WORKERS
+        );
+        require!(
+            task.consensus_policy.is_compatible(task.max_workers), E_INCOMPATIBLE_POLICY
+        );
+        if task.escalation.max_rounds > 0 {
+            require!(
+                task.escalation.extra_workers >= 1, E_INVALID_ESCALATION
+            );
+            require!(
+                task.escalation.reserve_bps <= admin::MAX_BPS, E_INVALID_ESCALATION_RESERVE
+            );
+            // the policy has to stay safe for the enlarged vote set too
+            let max_escalated_workers = task.escalation.extra_workers
+                .saturating_mul(task.escalation.max_rounds as usize)
+                .saturating_add(task.max_workers);
+            require!(
+                max_escalated_workers <= MAX_WORKERS, E_TOO_MANY_WORKERS
+            );
+            require!(
+                task.consensus_policy.is_compatible(max_escalated_workers), E_INCOMPATIBLE_POLICY
+            );
+        }
+
+        require!(
+            task.parent_task_ids.len() <= MAX_PARENT_TASKS, E_TOO_MANY_PARENTS
+        );
+        require!(
+            task.worker_group.len() <= workers::MAX_GROUP_NAME_LENGTH, E_INVALID_GROUP_NAME
+        );
+
+        self.escrowed_amount(&task.reward_token).update(|amount| *amount += &task.reward_amount);
+
+        let task_id = self.task_id_counter().get();
+        for parent_id in task.parent_task_ids.iter() {
+            require!(
+                !self.tasks(parent_id).is_empty(), E_PARENT_NOT_FOUND
+            );
+            require!(
+                task.parent_task_ids.iter().filter(|id| *id == parent_id).count() == 1, E_DUPLICATE_PARENT
+            );
+            let parent_status = self.tasks(parent_id).get().status;
+            require!(
+                parent_status != TaskStatus::Failed && parent_status != TaskStatus::Expired && parent_status != TaskStatus::Cancelled,
+                E_PARENT_FAILED
+            );
+            if parent_status != TaskStatus::Completed {
+                task.status = TaskStatus::Pending;
+            }
+        }
+
+        let now = self.blockchain().get_block_timestamp();
+        self.task_schedule(task_id).set((task.commit_deadline - now, task.deadline - task.commit_deadline));
+        if task.status == TaskStatus::Pending {
+            task.commit_deadline = 0;
+            task.deadline = 0;
+        }
+
+        self.tasks(task_id).set(&task);
+        self.task_id_counter().update(|id| *id +=1);
+        self.tasks_by_status(&task.status).insert(task_id);
+        self.creator_tasks(&task.creator).push(&task_id);
+
+        self.emit_task_posted_event(task_id, &task.creator, task.max_workers, task.deadline, &task.reward_token, &task.reward_amount);
+        task_id
+    }
+
+    fn is_worker_allowed(&self, task_id: u64, task: &Task<Self::Api>, worker: &ManagedAddress) -> bool {
+        !task.private
+            || self.task_allow_list(task_id).contains(worker)
+            || (!task.worker_group.is_empty() && self.worker_groups(&task.creator, &task.worker_group).contains(worker))
+    }
+
+    fn require_editable_task(&self, task_id: u64) -> Task<Self::Api> {
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
+        let task = self.tasks(task_id).get();
+        require!(
+            self.blockchain().get_caller() == task.creator, E_NOT_TASK_CREATOR
+        );
+        require!(
+            task.status == TaskStatus::Open || task.status == TaskStatus::Pending, E_TASK_NOT_OPEN
+        );
+        task
+    }
+
+    fn require_valid_reward_token(&self, reward_token: &EgldOrEsdtTokenIdentifier){
+        require!(
+            reward_token.is_egld() || self.accepted_tokens().contains(&reward_token.clone().unwrap_esdt()),
+            E_TOKEN_NOT_ACCEPTED
+        );
+    }
+
+    // the durations have no upper bound, every deadline computed from them saturates so a huge one cant wrap around into the past
+    fn require_valid_terms(&self, duration: u64, reveal_duration: u64, lease_duration: u64, participation_bps: u64){
+        require!(
+            duration > 0, E_INVALID_DURATION
+        );
+        require!(
+            reveal_duration > 0, E_INVALID_REVEAL_DURATION
+        );
+        require!(
+            lease_duration > 0, E_INVALID_LEASE_DURATION
+        );
+        require!(
+            participation_bps <= admin::MAX_BPS, E_INVALID_PARTICIPATION
+        );
+    }
+
+    fn require_valid_spec(&self, spec: &TaskSpec<Self::Api>){
+        require!(
+            !spec.docker_image_uri.is_empty() && spec.docker_image_uri.len() <= MAX_URI_LENGTH, E_INVALID_IMAGE_URI
+        );
+        require!(
+            !spec.input_data_uri.is_empty() && spec.input_data_uri.len() <= MAX_URI_LENGTH, E_INVALID_INPUT_URI
+        );
+
+        require!(
+            spec.image_digest.len() == IMAGE_DIGEST_LENGTH, E_INVALID_IMAGE_DIGEST
+        );
+        let mut digest = [0u8; IMAGE_DIGEST_LENGTH];
+        let (prefix, hex) = spec.image_digest.load_to_byte_array(&mut digest).split_at(IMAGE_DIGEST_PREFIX.len());
+        require!(
+            prefix == IMAGE_DIGEST_PREFIX && hex.iter().all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f')),
+            E_INVALID_IMAGE_DIGEST
+        );
+
+        require!(
+            spec.entrypoint.len() <= MAX_ENTRYPOINT_LENGTH, E_INVALID_ENTRYPOINT
+        );
+        require!(
+            spec.arguments.len() <= MAX_ARGUMENTS, E_TOO_MANY_ARGUMENTS
+        );
+        for argument in spec.arguments.iter() {
+            require!(
+                argument.len() <= MAX_ARGUMENT_LENGTH, E_INVALID_ARGUMENT
+            );
+        }
+        require!(
+            !spec.output_format.is_empty() && spec.output_format.len() <= MAX_OUTPUT_FORMAT_LENGTH, E_INVALID_OUTPUT_FORMAT
+        );
+    }
+
+    // what each revealed worker gets out of `pool` for taking part, regardless of the outcome
+    fn participation_share(&self, task: &Task<Self::Api>, pool: &BigUint) -> BigUint {
+        if task.revealed_count == 0 {
+            return BigUint::zero();
+        }
+        pool * task.participation_bps / admin::MAX_BPS / (task.revealed_count as u64)
+    }
+
+    // drops the leases that ran out so their seats can be taken again, their stakes go to the creator
+    // returns how many are still active
+    fn release_expired_leases(&self, task_id: u64, task: &Task<Self::Api>) -> usize {
+        let now = self.blockchain().get_block_timestamp();
+        let mut leases = self.task_leases(task_id);
+
+        let mut expired = ManagedVec::<Self::Api, ManagedAddress>::new();
+        for (worker, lease_expiry) in leases.iter() {
+            if lease_expiry <= now {
+                expired.push(worker);
+            }
+        }
+        // the input key was only meant for a seat holder, the next one gets their own
+        for worker in expired.iter() {
+            leases.remove(&worker);
+            self.encrypted_input_keys(task_id, &worker).clear();
+        }
+        let forfeited = &task.worker_stake * (expired.len() as u64);
+        self.credit(&task.creator, &EgldOrEsdtTokenIdentifier::egld(), &forfeited);
+
+        leases.len()
+    }
+
+    // keeps the status index in sync, every status transition after posting has to go through here
+    fn change_status(&self, task_id: u64, task: &mut Task<Self::Api>, status: TaskStatus){
+        self.tasks_by_status(&task.status).swap_remove(&task_id);
+        self.tasks_by_status(&status).insert(task_id);
+        task.status = status;
+    }
+
+    fn return_stake(&self, worker: &ManagedAddress, stake: &BigUint){
+        self.credit(worker, &EgldOrEsdtTokenIdentifier::egld(), stake);
+    }
+
+    // gives back the unspent part of the reward, each sponsor gets the share of it they contributed and the creator the rest
+    fn refund_contributors(&self, task: &Task<Self::Api>, task_id: u64, refund_amount: &BigUint){
+        let mut creator_refund = refund_amount.clone();
+        for (sponsor, contributed) in self.reward_contributions(task_id).iter() {
+            let sponsor_refund = refund_amount * &contributed / &task.reward_amount;
+            self.credit_reward(task, &sponsor, &sponsor_refund);
+            creator_refund -= &sponsor_refund;
+        }
+        self.credit_reward(task, &task.creator, &creator_refund);
+    }
+
+    // rewards and refunds are credited in the token the task was funded with, stakes are always EGLD
+    fn credit_reward(&self, task: &Task<Self::Api>, to: &ManagedAddress, amount: &BigUint){
+        self.credit(to, &task.reward_token, amount);
+    }
+
+    // nothing is sent during finalization, the amount moves from the task escrow to the address' claimable balance
+    // every payout goes through here so the escrow stays in sync with the balance
+    fn credit(&self, to: &ManagedAddress, token: &EgldOrEsdtTokenIdentifier, amount: &BigUint){
+        if *amount == 0 {
+            return;
+        }
+        self.escrowed_amount(token).update(|escrowed| *escrowed -= amount);
+        self.claimable(to, token).update(|claimable| *claimable += amount);
+        self.claimable_tokens(to).insert(token.clone());
+        self.total_claimable(token).update(|total| *total += amount);
+    }
+
+
+    #[view(getTask)]
+    fn get_task(&self, task_id: u64) -> Task<Self::Api> {
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
+        self.tasks(task_id).get()
+    }
+
+    #[view(getTaskStatus)]
+    fn get_task_status(&self, task_id: u64) -> TaskStatus {
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
+        let task = self.tasks(task_id).get();
+        task.status
+    }
+
+    #[view(getTaskResult)]
+    fn get_task_result(&self, task_id: u64) -> TaskResult<Self::Api> {
+        require!(
+            !self.task_results(task_id).is_empty(), E_NO_RESULT
+        );
+        self.task_results(task_id).get()
+    }
+
+    #[view(getRewardContributions)]
+    fn get_reward_contributions(&self, task_id: u64) -> MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>> {
+        let mut contributions = MultiValueEncoded::new();
+        for (sponsor, amount) in self.reward_contributions(task_id).iter() {
+            contributions.push((sponsor, amount).into());
+        }
+        contributions
+    }
+
+    #[view(getTaskAllowList)]
+    fn get_task_allow_list(&self, task_id: u64) -> MultiValueEncoded<ManagedAddress> {
+        self.task_allow_list(task_id).iter().collect()
+    }
+
+    #[view(getEncryptedInputKey)]
+    fn get_encrypted_input_key(&self, task_id: u64, worker: ManagedAddress) -> ManagedBuffer {
+        self.encrypted_input_keys(task_id, &worker).get()
+    }
+
+    // the results of the completed parents, workers of a dependent task fetch their inputs from here
+    #[view(getParentResults)]
+    fn get_parent_results(&self, task_id: u64) -> MultiValueEncoded<MultiValue2<u64, TaskResult<Self::Api>>> {
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
+        let mut results = MultiValueEncoded::new();
+        for parent_id in self.tasks(task_id).get().parent_task_ids.iter() {
+            let result_mapper = self.task_results(parent_id);
+            if !result_mapper.is_empty() {
+                results.push((parent_id, result_mapper.get()).into());
+            }
+        }
+        results
+    }
+
+    // seats nobody committed to or holds an active lease on
+    #[view(getFreeSeats)]
+    fn get_free_seats(&self, task_id: u64) -> usize {
+        require!(
+            !self.tasks(task_id).is_empty(), E_TASK_NOT_FOUND
+        );
+        let task = self.tasks(task_id).get();
+        if task.status != TaskStatus::Open || self.blockchain().get_block_timestamp() >= task.commit_deadline {
+            return 0;
+        }
+
+        let now = self.blockchain().get_block_timestamp();
+        let active_leases = self.task_leases(task_id).values().filter(|lease_expiry| *lease_expiry > now).count();
+        task.max_workers - task.submissions_count - active_leases
+    }
+
+    // listing views, all of them return (task_id, task) pairs and at most MAX_PAGE_SIZE entries
+
+    #[view(getTasks)]
+    fn get_tasks(&self, from_task_id: u64, limit: usize) -> MultiValueEncoded<MultiValue2<u64, Task<Self::Api>>> {
+        let end_task_id = core::cmp::min(
+            from_task_id.saturating_add(self.page_size(limit) as u64),
+            self.task_id_counter().get(),
+        );
+
+        let mut result = MultiValueEncoded::new();
+        for task_id in from_task_id..end_task_id {
+            result.push((task_id, self.tasks(task_id).get()).into());
+        }
+        result
+    }
+
+    #[view(getTasksByStatus)]
+    fn get_tasks_by_status(&self, status: TaskStatus, offset: usize, limit: usize) -> MultiValueEncoded<MultiValue2<u64, Task<Self::Api>>> {
+        let index = self.tasks_by_status(&status);
+        let end = core::cmp::min(offset.saturating_add(self.page_size(limit)), index.len());
+
+        let mut result = MultiValueEncoded::new();
+        for i in offset..end {
+            let task_id = index.get_by_index(i + 1); // mapper indexes start at 1
+            result.push((task_id, self.tasks(task_id).get()).into());
+        }
+        result
+    }
+
+    #[view(getTasksByCreator)]
+    fn get_tasks_by_creator(&self, creator: ManagedAddress, offset: usize, limit: usize) -> MultiValueEncoded<MultiValue2<u64, Task<Self::Api>>> {
+        self.tasks_page(&self.creator_tasks(&creator), offset, limit)
+    }
+
+    #[view(getTasksByWorker)]
+    fn get_tasks_by_worker(&self, worker: ManagedAddress, offset: usize, limit: usize) -> MultiValueEncoded<MultiValue2<u64, Task<Self::Api>>> {
+        self.tasks_page(&self.worker_tasks(&worker), offset, limit)
+    }
+
+    fn tasks_page(&self, index: &VecMapper<u64>, offset: usize, limit: usize) -> MultiValueEncoded<MultiValue2<u64, Task<Self::Api>>> {
+        let end = core::cmp::min(offset.saturating_add(self.page_size(limit)), index.len());
+
+        let mut result = MultiValueEncoded::new();
+        for i in offset..end {
+            let task_id = index.get(i + 1);
+            result.push((task_id, self.tasks(task_id).get()).into());
+        }
+        result
+    }
+
+    fn page_size(&self, limit: usize) -> usize {
+        core::cmp::min(limit, MAX_PAGE_SIZE)
+    }
+
+    #[view(getClaimable)]
+    fn get_claimable(&self, address: ManagedAddress) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for token in self.claimable_tokens(&address).iter() {
+            let amount = self.claimable(&address, &token).get();
+            result.push((token, amount).into());
+        }
+        result
+    }
+
+    // returns (unallocated balance, escrowed amount, claimable amount) for a token
+    // unallocated should always be 0 unless someone sent funds directly to the contract, anything else means funds leaked
+    #[view(getFundsAudit)]
+    fn get_funds_audit(&self, token: EgldOrEsdtTokenIdentifier) -> MultiValue3<BigUint, BigUint, BigUint> {
+        let balance = self.blockchain().get_sc_balance(&token, 0);
+        let escrowed = self.escrowed_amount(&token).get();
+        let claimable = self.total_claimable(&token).get();
+        let allocated = &escrowed + &claimable;
+        let unallocated = if balance > allocated { &balance - &allocated } else { BigUint::zero() };
+
+        (unallocated, escrowed, claimable).into()
     }
 
 }
@@ -0,0 +1,79 @@
+// stable error identifiers, every require! in the contract fails with one of these as its message
+// clients match on them, so an identifier is never renamed or reused for another failure once released
+// (the owner-only endpoints still fail with the framework's own "Endpoint can only be called by owner")
+
+// admin
+pub const E_PAUSED: &str = "E_PAUSED";
+pub const E_INVALID_PROTOCOL_FEE: &str = "E_INVALID_PROTOCOL_FEE";
+pub const E_INVALID_TOKEN: &str = "E_INVALID_TOKEN";
+
+// posting and editing tasks
+pub const E_ZERO_REWARD: &str = "E_ZERO_REWARD";
+pub const E_TOKEN_NOT_ACCEPTED: &str = "E_TOKEN_NOT_ACCEPTED";
+pub const E_WRONG_PAYMENT_TOKEN: &str = "E_WRONG_PAYMENT_TOKEN";
+pub const E_NO_WORKERS: &str = "E_NO_WORKERS";
+pub const E_TOO_MANY_WORKERS: &str = "E_TOO_MANY_WORKERS";
+pub const E_INCOMPATIBLE_POLICY: &str = "E_INCOMPATIBLE_POLICY";
+pub const E_INVALID_DURATION: &str = "E_INVALID_DURATION";
+pub const E_INVALID_REVEAL_DURATION: &str = "E_INVALID_REVEAL_DURATION";
+pub const E_INVALID_LEASE_DURATION: &str = "E_INVALID_LEASE_DURATION";
+pub const E_INVALID_PARTICIPATION: &str = "E_INVALID_PARTICIPATION";
+pub const E_INVALID_ESCALATION: &str = "E_INVALID_ESCALATION";
+pub const E_INVALID_ESCALATION_RESERVE: &str = "E_INVALID_ESCALATION_RESERVE";
+pub const E_INVALID_IMAGE_URI: &str = "E_INVALID_IMAGE_URI";
+pub const E_INVALID_IMAGE_DIGEST: &str = "E_INVALID_IMAGE_DIGEST";
+pub const E_INVALID_INPUT_URI: &str = "E_INVALID_INPUT_URI";
+pub const E_INVALID_ENTRYPOINT: &str = "E_INVALID_ENTRYPOINT";
+pub const E_TOO_MANY_ARGUMENTS: &str = "E_TOO_MANY_ARGUMENTS";
+pub const E_INVALID_ARGUMENT: &str = "E_INVALID_ARGUMENT";
+pub const E_INVALID_OUTPUT_FORMAT: &str = "E_INVALID_OUTPUT_FORMAT";
+pub const E_INVALID_GROUP_NAME: &str = "E_INVALID_GROUP_NAME";
+pub const E_TOO_MANY_PARENTS: &str = "E_TOO_MANY_PARENTS";
+pub const E_PARENT_NOT_FOUND: &str = "E_PARENT_NOT_FOUND";
+pub const E_DUPLICATE_PARENT: &str = "E_DUPLICATE_PARENT";
+pub const E_PARENT_FAILED: &str = "E_PARENT_FAILED";
+pub const E_PARENTS_NOT_FINISHED: &str = "E_PARENTS_NOT_FINISHED";
+pub const E_EMPTY_BATCH: &str = "E_EMPTY_BATCH";
+pub const E_SHARES_MISMATCH: &str = "E_SHARES_MISMATCH";
+pub const E_TOO_MANY_SPONSORS: &str = "E_TOO_MANY_SPONSORS";
+pub const E_INVALID_ENCRYPTED_KEY: &str = "E_INVALID_ENCRYPTED_KEY";
+
+// task lifecycle
+pub const E_TASK_NOT_FOUND: &str = "E_TASK_NOT_FOUND";
+pub const E_TASK_NOT_OPEN: &str = "E_TASK_NOT_OPEN";
+pub const E_TASK_NOT_PENDING: &str = "E_TASK_NOT_PENDING";
+pub const E_NOT_TASK_CREATOR: &str = "E_NOT_TASK_CREATOR";
+pub const E_COMMIT_PHASE_ENDED: &str = "E_COMMIT_PHASE_ENDED";
+pub const E_REVEAL_NOT_STARTED: &str = "E_REVEAL_NOT_STARTED";
+pub const E_DEADLINE_PASSED: &str = "E_DEADLINE_PASSED";
+pub const E_DEADLINE_NOT_PASSED: &str = "E_DEADLINE_NOT_PASSED";
+pub const E_TASK_NOT_FINISHED: &str = "E_TASK_NOT_FINISHED";
+pub const E_NO_RESULT: &str = "E_NO_RESULT";
+
+// workers
+pub const E_REPUTATION_TOO_LOW: &str = "E_REPUTATION_TOO_LOW";
+pub const E_WORKER_NOT_ALLOWED: &str = "E_WORKER_NOT_ALLOWED";
+pub const E_WORKER_NOT_ASSIGNED: &str = "E_WORKER_NOT_ASSIGNED";
+pub const E_NO_ENCRYPTION_KEY: &str = "E_NO_ENCRYPTION_KEY";
+pub const E_INVALID_ENCRYPTION_KEY: &str = "E_INVALID_ENCRYPTION_KEY";
+pub const E_LEASE_ALREADY_HELD: &str = "E_LEASE_ALREADY_HELD";
+pub const E_NO_FREE_SEATS: &str = "E_NO_FREE_SEATS";
+pub const E_WRONG_STAKE: &str = "E_WRONG_STAKE";
+pub const E_DUPLICATE_SUBMISSION: &str = "E_DUPLICATE_SUBMISSION";
+pub const E_DUPLICATE_COMMITMENT: &str = "E_DUPLICATE_COMMITMENT";
+pub const E_NO_COMMITMENT: &str = "E_NO_COMMITMENT";
+pub const E_DUPLICATE_REVEAL: &str = "E_DUPLICATE_REVEAL";
+pub const E_EMPTY_RESULT_HASH: &str = "E_EMPTY_RESULT_HASH";
+pub const E_INVALID_OUTPUT_URI: &str = "E_INVALID_OUTPUT_URI";
+pub const E_COMMITMENT_MISMATCH: &str = "E_COMMITMENT_MISMATCH";
+pub const E_NOTHING_TO_CLAIM: &str = "E_NOTHING_TO_CLAIM";
+
+// disputes
+pub const E_TASK_NOT_VERIFIED: &str = "E_TASK_NOT_VERIFIED";
+pub const E_TASK_NOT_DISPUTED: &str = "E_TASK_NOT_DISPUTED";
+pub const E_DISPUTE_PERIOD_ENDED: &str = "E_DISPUTE_PERIOD_ENDED";
+pub const E_DISPUTE_PERIOD_ACTIVE: &str = "E_DISPUTE_PERIOD_ACTIVE";
+pub const E_ARBITRATION_PERIOD_ACTIVE: &str = "E_ARBITRATION_PERIOD_ACTIVE";
+pub const E_NO_ARBITER: &str = "E_NO_ARBITER";
+pub const E_NOT_ARBITER: &str = "E_NOT_ARBITER";
+pub const E_WRONG_DISPUTE_BOND: &str = "E_WRONG_DISPUTE_BOND";
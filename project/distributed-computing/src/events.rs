@@ -0,0 +1,184 @@
+#[allow(unused_imports)]
+use multiversx_sc::imports::*;
+
+
+#[multiversx_sc::module]
+pub trait DistributedComputingEvents{
+
+    #[event("task_posted")]
+    fn emit_task_posted_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] creator: &ManagedAddress<Self::Api>,
+        #[indexed] max_workers: usize,
+        #[indexed] deadline: u64,
+        #[indexed] reward_token: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        reward_amount: &BigUint<Self::Api>,
+    );
+
+    #[event("task_claimed")]
+    fn emit_task_claimed_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] worker: &ManagedAddress<Self::Api>,
+        lease_expiry: u64,
+    );
+
+    #[event("result_committed")]
+    fn emit_result_committed_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] worker: &ManagedAddress<Self::Api>,
+        commitment: &ManagedBuffer<Self::Api>,
+    );
+
+    #[event("result_submitted")]
+    fn emit_result_submitted_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] worker: &ManagedAddress<Self::Api>,
+        result_hash: &ManagedBuffer<Self::Api>,
+    );
+
+    #[event("verification_started")]
+    fn emit_verification_started_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] submissions_count: usize,
+    );
+
+    #[event("consensus_reached")]
+    fn emit_consensus_reached_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] winning_hash: &ManagedBuffer<Self::Api>,
+        #[indexed] winner_count: usize,
+    );
+
+    // per worker amounts: participation share for every revealed worker, winner share on top of it for the winners
+    #[event("reward_split")]
+    fn emit_reward_split_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] participation_share: &BigUint<Self::Api>,
+        #[indexed] winner_share: &BigUint<Self::Api>,
+    );
+
+    #[event("reward_paid")]
+    fn emit_reward_paid_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] worker: &ManagedAddress<Self::Api>,
+        amount: &BigUint<Self::Api>,
+    );
+
+    #[event("protocol_fee_paid")]
+    fn emit_protocol_fee_paid_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] treasury: &ManagedAddress<Self::Api>,
+        amount: &BigUint<Self::Api>,
+    );
+
+    #[event("rewards_claimed")]
+    fn emit_rewards_claimed_event(
+        &self,
+        #[indexed] address: &ManagedAddress<Self::Api>,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier<Self::Api>,
+        amount: &BigUint<Self::Api>,
+    );
+
+    #[event("task_failed")]
+    fn emit_task_failed_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] creator: &ManagedAddress<Self::Api>,
+        refund_amount: &BigUint<Self::Api>,
+    );
+
+    #[event("task_cancelled")]
+    fn emit_task_cancelled_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] creator: &ManagedAddress<Self::Api>,
+        refund_amount: &BigUint<Self::Api>,
+    );
+
+    #[event("worker_registered")]
+    fn emit_worker_registered_event(
+        &self,
+        #[indexed] worker: &ManagedAddress<Self::Api>,
+        metadata: &ManagedBuffer<Self::Api>,
+    );
+
+    #[event("task_expired")]
+    fn emit_task_expired_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] submissions_count: usize,
+    );
+
+    #[event("reward_increased")]
+    fn emit_reward_increased_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] sponsor: &ManagedAddress<Self::Api>,
+        #[indexed] amount: &BigUint<Self::Api>,
+        reward_amount: &BigUint<Self::Api>,
+    );
+
+    #[event("task_escalated")]
+    fn emit_task_escalated_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] round: u32,
+        #[indexed] max_workers: usize,
+        #[indexed] deadline: u64,
+    );
+
+    #[event("input_key_attached")]
+    fn emit_input_key_attached_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] worker: &ManagedAddress<Self::Api>,
+    );
+
+    #[event("dispute_opened")]
+    fn emit_dispute_opened_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] creator: &ManagedAddress<Self::Api>,
+        bond: &BigUint<Self::Api>,
+    );
+
+    #[event("dispute_resolved")]
+    fn emit_dispute_resolved_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] arbiter: &ManagedAddress<Self::Api>,
+        #[indexed] result_reversed: bool,
+    );
+
+    // nobody resolved the dispute within the arbitration period, the result was confirmed and the bond returned
+    #[event("dispute_timed_out")]
+    fn emit_dispute_timed_out_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] creator: &ManagedAddress<Self::Api>,
+        bond: &BigUint<Self::Api>,
+    );
+
+    #[event("task_pruned")]
+    fn emit_task_pruned_event(
+        &self,
+        #[indexed] task_id: u64,
+    );
+
+    #[event("task_activated")]
+    fn emit_task_activated_event(
+        &self,
+        #[indexed] task_id: u64,
+        #[indexed] commit_deadline: u64,
+        #[indexed] deadline: u64,
+    );
+}
@@ -0,0 +1,131 @@
+#[allow(unused_imports)]
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::errors::*;
+
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug, PartialEq)]
+pub struct WorkerStats<M: ManagedTypeApi>{
+    pub registered: bool,
+    pub metadata: ManagedBuffer<M>, // free form, e.g. supported hardware / capabilities
+    pub submissions: u64, // tasks finalized with a commitment from this worker
+    pub wins: u64, // revealed the winning hash
+    pub losses: u64, // revealed a hash that lost the consensus
+    pub expired_commitments: u64, // committed but never revealed
+}
+
+impl<M: ManagedTypeApi> WorkerStats<M> {
+    // every loss or missed reveal cancels out one win
+    // not Sybil-resistant: nothing stops one party from posting tasks from one address and winning them with another
+    pub fn reputation(&self) -> u64 {
+        self.wins.saturating_sub(self.losses + self.expired_commitments)
+    }
+}
+
+
+pub const MAX_GROUP_NAME_LENGTH: usize = 64;
+pub const MAX_ENCRYPTION_KEY_LENGTH: usize = 128;
+
+
+#[multiversx_sc::module]
+pub trait WorkersModule: crate::events::DistributedComputingEvents {
+
+    #[storage_mapper("worker_stats")]
+    fn worker_stats(&self, worker: &ManagedAddress) -> SingleValueMapper<WorkerStats<Self::Api>>;
+
+    // registering is optional, but needed for tasks that ask for a minimum reputation
+    // calling it again only updates the metadata
+    #[endpoint(registerWorker)]
+    fn register_worker(&self, metadata: ManagedBuffer){
+        let caller = self.blockchain().get_caller();
+        let mut stats = self.get_worker_stats(caller.clone());
+        stats.registered = true;
+        stats.metadata = metadata;
+        self.worker_stats(&caller).set(&stats);
+
+        self.emit_worker_registered_event(&caller, &stats.metadata);
+    }
+
+    // public key creators encrypt the input keys of private tasks with, the scheme is agreed off-chain (e.g. X25519)
+    #[view(getWorkerEncryptionKey)]
+    #[storage_mapper("worker_encryption_keys")]
+    fn worker_encryption_keys(&self, worker: &ManagedAddress) -> SingleValueMapper<ManagedBuffer>;
+
+    #[endpoint(setEncryptionKey)]
+    fn set_encryption_key(&self, public_key: ManagedBuffer){
+        require!(
+            !public_key.is_empty() && public_key.len() <= MAX_ENCRYPTION_KEY_LENGTH, E_INVALID_ENCRYPTION_KEY
+        );
+        self.worker_encryption_keys(&self.blockchain().get_caller()).set(&public_key);
+    }
+
+    // named sets of workers managed by whoever creates them, tasks can be restricted to a group of their creator
+    #[storage_mapper("worker_groups")]
+    fn worker_groups(&self, manager: &ManagedAddress, name: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[endpoint(addWorkersToGroup)]
+    fn add_workers_to_group(&self, name: ManagedBuffer, workers: MultiValueEncoded<ManagedAddress>){
+        require!(
+            !name.is_empty() && name.len() <= MAX_GROUP_NAME_LENGTH, E_INVALID_GROUP_NAME
+        );
+        let mut group = self.worker_groups(&self.blockchain().get_caller(), &name);
+        for worker in workers {
+            group.insert(worker);
+        }
+    }
+
+    #[endpoint(removeWorkersFromGroup)]
+    fn remove_workers_from_group(&self, name: ManagedBuffer, workers: MultiValueEncoded<ManagedAddress>){
+        let mut group = self.worker_groups(&self.blockchain().get_caller(), &name);
+        for worker in workers {
+            group.swap_remove(&worker);
+        }
+    }
+
+    #[view(getWorkerGroup)]
+    fn get_worker_group(&self, manager: ManagedAddress, name: ManagedBuffer) -> MultiValueEncoded<ManagedAddress> {
+        self.worker_groups(&manager, &name).iter().collect()
+    }
+
+    #[view(getWorkerStats)]
+    fn get_worker_stats(&self, worker: ManagedAddress) -> WorkerStats<Self::Api> {
+        let mapper = self.worker_stats(&worker);
+        if mapper.is_empty() {
+            return WorkerStats {
+                registered: false,
+                metadata: ManagedBuffer::new(),
+                submissions: 0,
+                wins: 0,
+                losses: 0,
+                expired_commitments: 0,
+            };
+        }
+        mapper.get()
+    }
+
+    #[view(getWorkerReputation)]
+    fn get_worker_reputation(&self, worker: ManagedAddress) -> u64 {
+        self.get_worker_stats(worker).reputation()
+    }
+
+    fn is_worker_eligible(&self, worker: &ManagedAddress, min_reputation: u64) -> bool {
+        if min_reputation == 0 {
+            return true;
+        }
+        let stats = self.get_worker_stats(worker.clone());
+        stats.registered && stats.reputation() >= min_reputation
+    }
+
+    // work on the workers own tasks is not counted, otherwise reputation could be farmed with tiny self-posted tasks
+    fn update_worker_stats<F: FnOnce(&mut WorkerStats<Self::Api>)>(&self, worker: &ManagedAddress, task_creator: &ManagedAddress, f: F){
+        if worker == task_creator {
+            return;
+        }
+        let mut stats = self.get_worker_stats(worker.clone());
+        stats.submissions += 1;
+        f(&mut stats);
+        self.worker_stats(worker).set(&stats);
+    }
+}
@@ -0,0 +1,1443 @@
+#[path = "../output/distributed_computing_proxy.rs"]
+mod proxy;
+
+use multiversx_sc::contract_base::CryptoWrapper;
+use multiversx_sc_scenario::imports::*;
+
+const OWNER: TestAddress = TestAddress::new("owner");
+const CREATOR: TestAddress = TestAddress::new("creator");
+const W1: TestAddress = TestAddress::new("w1");
+const W2: TestAddress = TestAddress::new("w2");
+const W3: TestAddress = TestAddress::new("w3");
+const ARBITER: TestAddress = TestAddress::new("arbiter");
+const SC: TestSCAddress = TestSCAddress::new("distributed-computing");
+const CODE: MxscPath = MxscPath::new("output/distributed-computing.mxsc.json");
+const TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("TOK-123456");
+const BALANCE: u64 = 1_000_000;
+const SALT: &str = "salt";
+const NO_ESCALATION: proxy::EscalationPolicy = proxy::EscalationPolicy {
+    extra_workers: 0,
+    max_rounds: 0,
+    reserve_bps: 0,
+};
+
+// the owner deploys with itself as treasury and no protocol fee, every account starts with BALANCE EGLD and TOKEN
+fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+    world.register_contract(CODE, distributed_computing::ContractBuilder);
+    for account in [OWNER, CREATOR, W1, W2, W3] {
+        world.account(account).nonce(1).balance(BALANCE).esdt_balance(TOKEN, BALANCE);
+    }
+    world
+        .tx()
+        .from(OWNER)
+        .typed(proxy::DistributedComputingProxy)
+        .init(OWNER, 0u64)
+        .code(CODE)
+        .new_address(SC)
+        .run();
+    world
+}
+
+fn call<'w, 'a>(
+    world: &'w mut ScenarioWorld,
+    from: TestAddress<'a>,
+) -> proxy::DistributedComputingProxyMethods<ScenarioEnvExec<'w>, TestAddress<'a>, TestSCAddress<'static>, ()> {
+    world.tx().from(from).to(SC).typed(proxy::DistributedComputingProxy)
+}
+
+fn spec() -> proxy::TaskSpec<StaticApi> {
+    proxy::TaskSpec {
+        docker_image_uri: ManagedBuffer::from("registry/worker"),
+        image_digest: ManagedBuffer::from("sha256:0000000000000000000000000000000000000000000000000000000000000000"),
+        input_data_uri: ManagedBuffer::from("ipfs://input"),
+        entrypoint: ManagedBuffer::new(),
+        arguments: ManagedVec::new(),
+        output_format: ManagedBuffer::from("json"),
+        resources: proxy::ResourceHints {
+            cpu_millicores: 0,
+            memory_mb: 0,
+            time_limit_seconds: 0,
+        },
+        result_encoding_version: 1,
+    }
+}
+
+fn public() -> proxy::TaskAccess<StaticApi> {
+    proxy::TaskAccess {
+        allow_list: ManagedVec::new(),
+        worker_group: ManagedBuffer::new(),
+    }
+}
+
+// the postTask arguments, tests start from the defaults and only set what they are about
+struct TaskTerms {
+    spec: proxy::TaskSpec<StaticApi>,
+    max_workers: usize,
+    consensus_policy: proxy::ConsensusPolicy,
+    duration: u64,
+    reveal_duration: u64,
+    lease_duration: u64,
+    worker_stake: u64,
+    min_reputation: u64,
+    participation_bps: u64,
+    escalation: proxy::EscalationPolicy,
+    dispute_period: u64,
+    access: proxy::TaskAccess<StaticApi>,
+    parent_task_ids: Vec<u64>,
+    reward_token: EgldOrEsdtTokenIdentifier<StaticApi>,
+    reward: u64,
+}
+
+// one public seat under simple majority, 50s to commit, 50s to reveal, 20s leases, no stake, participation,
+// escalation or dispute period, rewarded with 100 EGLD
+impl Default for TaskTerms {
+    fn default() -> Self {
+        TaskTerms {
+            spec: spec(),
+            max_workers: 1,
+            consensus_policy: proxy::ConsensusPolicy::SimpleMajority,
+            duration: 50,
+            reveal_duration: 50,
+            lease_duration: 20,
+            worker_stake: 0,
+            min_reputation: 0,
+            participation_bps: 0,
+            escalation: NO_ESCALATION,
+            dispute_period: 0,
+            access: public(),
+            parent_task_ids: Vec::new(),
+            reward_token: EgldOrEsdtTokenIdentifier::egld(),
+            reward: 100,
+        }
+    }
+}
+
+fn post_task(world: &mut ScenarioWorld, terms: TaskTerms) {
+    // status 0 with an empty message is a successful call
+    post_task_expecting(world, terms, ExpectError(0, ""));
+}
+
+fn post_task_expecting(world: &mut ScenarioWorld, terms: TaskTerms, expected: ExpectError) {
+    call(world, CREATOR)
+        .post_task(
+            terms.spec,
+            terms.max_workers,
+            terms.consensus_policy,
+            terms.duration,
+            terms.reveal_duration,
+            terms.lease_duration,
+            terms.worker_stake,
+            terms.min_reputation,
+            terms.participation_bps,
+            terms.escalation,
+            terms.dispute_period,
+            terms.access,
+            MultiValueVec::from(terms.parent_task_ids),
+        )
+        .payment(EgldOrEsdtTokenPayment::new(terms.reward_token, 0, BigUint::from(terms.reward)))
+        .returns(expected)
+        .run();
+}
+
+fn commitment(result_hash: &str, worker: TestAddress) -> ManagedBuffer<StaticApi> {
+    salted_commitment(result_hash, SALT, worker)
+}
+
+// keccak256(keccak256(result_hash) || salt || worker address)
+fn salted_commitment(result_hash: &str, salt: &str, worker: TestAddress) -> ManagedBuffer<StaticApi> {
+    let crypto = CryptoWrapper::<StaticApi>::new();
+    let mut preimage = crypto.keccak256(ManagedBuffer::<StaticApi>::from(result_hash)).as_managed_buffer().clone();
+    preimage.append(&ManagedBuffer::from(salt));
+    preimage.append(worker.to_managed_address::<StaticApi>().as_managed_buffer());
+    crypto.keccak256(&preimage).as_managed_buffer().clone()
+}
+
+fn commit(world: &mut ScenarioWorld, worker: TestAddress, task_id: u64, result_hash: &str, stake: u64) {
+    call(world, worker)
+        .submit_result(task_id, commitment(result_hash, worker))
+        .egld(stake)
+        .run();
+}
+
+fn reveal(world: &mut ScenarioWorld, worker: TestAddress, task_id: u64, result_hash: &str) {
+    call(world, worker)
+        .reveal_result(task_id, ManagedBuffer::from(result_hash), ManagedBuffer::from(SALT), ManagedBuffer::new())
+        .run();
+}
+
+fn status(world: &mut ScenarioWorld, task_id: u64) -> proxy::TaskStatus {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::DistributedComputingProxy)
+        .get_task_status(task_id)
+        .returns(ReturnsResult)
+        .run()
+}
+
+// payouts are only credited, withdraw everything so the balances can be checked
+fn claim_all(world: &mut ScenarioWorld) {
+    for account in [OWNER, CREATOR, W1, W2, W3] {
+        let claimable = world
+            .query()
+            .to(SC)
+            .typed(proxy::DistributedComputingProxy)
+            .get_claimable(account.to_managed_address())
+            .returns(ReturnsResultUnmanaged)
+            .run();
+        if !claimable.is_empty() {
+            call(world, account).claim_rewards().run();
+        }
+    }
+}
+
+#[test]
+fn partial_reveals_expire_without_a_majority() {
+    let mut world = world();
+    world.current_block().block_timestamp(100);
+    post_task(
+        &mut world,
+        TaskTerms {
+            max_workers: 3,
+            worker_stake: 10,
+            reward: 900,
+            ..TaskTerms::default()
+        },
+    );
+    commit(&mut world, W1, 0, "a", 10);
+    commit(&mut world, W2, 0, "a", 10);
+
+    call(&mut world, W3)
+        .expire_task(0u64)
+        .returns(ExpectError(4, "E_DEADLINE_NOT_PASSED"))
+        .run();
+
+    // only w1 reveals, 1 vote out of 3 seats is not a majority
+    world.current_block().block_timestamp(150);
+    reveal(&mut world, W1, 0, "a");
+    world.current_block().block_timestamp(200);
+    call(&mut world, W3).expire_task(0u64).run();
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Expired);
+
+    // the reward goes back, w1 gets its stake back and w2 forfeits its stake to the creator
+    claim_all(&mut world);
+    world.check_account(CREATOR).balance(BALANCE + 10);
+    world.check_account(W1).balance(BALANCE);
+    world.check_account(W2).balance(BALANCE - 10);
+    world.check_account(SC).balance(0u64);
+}
+
+#[test]
+fn wrong_result_stake_is_slashed_and_winners_split_the_reward() {
+    let mut world = world();
+    post_task(
+        &mut world,
+        TaskTerms {
+            max_workers: 3,
+            worker_stake: 100,
+            reward: 900,
+            ..TaskTerms::default()
+        },
+    );
+
+    call(&mut world, W1)
+        .submit_result(0u64, commitment("a", W1))
+        .egld(99u64)
+        .returns(ExpectError(4, "E_WRONG_STAKE"))
+        .run();
+    commit(&mut world, W1, 0, "a", 100);
+    commit(&mut world, W2, 0, "b", 100);
+    commit(&mut world, W3, 0, "a", 100);
+
+    call(&mut world, W2)
+        .reveal_result(0u64, ManagedBuffer::from("a"), ManagedBuffer::from(SALT), ManagedBuffer::new())
+        .returns(ExpectError(4, "E_COMMITMENT_MISMATCH"))
+        .run();
+    reveal(&mut world, W1, 0, "a");
+    reveal(&mut world, W2, 0, "b");
+    reveal(&mut world, W3, 0, "a");
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Completed);
+
+    // w1 and w3 split the reward and get their stake back, w2's stake goes to the creator
+    claim_all(&mut world);
+    world.check_account(W1).balance(BALANCE + 450);
+    world.check_account(W3).balance(BALANCE + 450);
+    world.check_account(W2).balance(BALANCE - 100);
+    world.check_account(CREATOR).balance(BALANCE - 900 + 100);
+    world.check_account(SC).balance(0u64);
+}
+
+#[test]
+fn cancel_compensates_committed_workers_and_refunds_the_rest() {
+    let mut world = world();
+    post_task(
+        &mut world,
+        TaskTerms {
+            max_workers: 3,
+            worker_stake: 100,
+            reward: 900,
+            ..TaskTerms::default()
+        },
+    );
+    commit(&mut world, W1, 0, "a", 100);
+    call(&mut world, W2).claim_task(0u64).egld(100u64).run();
+
+    call(&mut world, W1)
+        .cancel_task(0u64)
+        .returns(ExpectError(4, "E_NOT_TASK_CREATOR"))
+        .run();
+    call(&mut world, CREATOR).cancel_task(0u64).run();
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Cancelled);
+    call(&mut world, CREATOR)
+        .cancel_task(0u64)
+        .returns(ExpectError(4, "E_TASK_NOT_OPEN"))
+        .run();
+
+    // w1 committed and gets one seat's share of the reward, w2 only held a lease and gets its stake back
+    claim_all(&mut world);
+    world.check_account(W1).balance(BALANCE + 300);
+    world.check_account(W2).balance(BALANCE);
+    world.check_account(CREATOR).balance(BALANCE - 300);
+    world.check_account(SC).balance(0u64);
+}
+
+// (unallocated, escrowed, claimable) of the EGLD held by the contract
+fn funds_audit(world: &mut ScenarioWorld) -> (u64, u64, u64) {
+    let (unallocated, escrowed, claimable) = world
+        .query()
+        .to(SC)
+        .typed(proxy::DistributedComputingProxy)
+        .get_funds_audit(EgldOrEsdtTokenIdentifier::egld())
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple();
+    (
+        unallocated.try_into().unwrap(),
+        escrowed.try_into().unwrap(),
+        claimable.try_into().unwrap(),
+    )
+}
+
+#[test]
+fn division_remainder_goes_back_to_the_creator_and_funds_stay_accounted() {
+    let mut world = world();
+    post_task(
+        &mut world,
+        TaskTerms {
+            max_workers: 3,
+            worker_stake: 10,
+            reward: 1000,
+            ..TaskTerms::default()
+        },
+    );
+    commit(&mut world, W1, 0, "a", 10);
+    assert_eq!(funds_audit(&mut world), (0, 1010, 0));
+
+    commit(&mut world, W2, 0, "a", 10);
+    commit(&mut world, W3, 0, "a", 10);
+    for worker in [W1, W2, W3] {
+        reveal(&mut world, worker, 0, "a");
+    }
+
+    // 1000 does not split evenly between 3 winners, the remaining 1 is credited to the creator
+    assert_eq!(funds_audit(&mut world), (0, 0, 1030));
+    claim_all(&mut world);
+    for worker in [W1, W2, W3] {
+        world.check_account(worker).balance(BALANCE + 333);
+    }
+    world.check_account(CREATOR).balance(BALANCE - 999);
+    world.check_account(SC).balance(0u64);
+    assert_eq!(funds_audit(&mut world), (0, 0, 0));
+}
+
+#[test]
+fn commitments_are_bound_to_the_worker_and_cannot_be_copied() {
+    let mut world = world();
+    post_task(
+        &mut world,
+        TaskTerms {
+            max_workers: 3,
+            reward: 900,
+            ..TaskTerms::default()
+        },
+    );
+    commit(&mut world, W1, 0, "a", 0);
+
+    // w2 copies w1's commitment from the chain without knowing the result
+    call(&mut world, W2)
+        .submit_result(0u64, commitment("a", W1))
+        .returns(ExpectError(4, "E_DUPLICATE_COMMITMENT"))
+        .run();
+    // a commitment built for another address can be submitted but never revealed
+    call(&mut world, W2).submit_result(0u64, commitment("a", OWNER)).run();
+    commit(&mut world, W3, 0, "a", 0);
+
+    reveal(&mut world, W1, 0, "a");
+    call(&mut world, W2)
+        .reveal_result(0u64, ManagedBuffer::from("a"), ManagedBuffer::from(SALT), ManagedBuffer::new())
+        .returns(ExpectError(4, "E_COMMITMENT_MISMATCH"))
+        .run();
+    reveal(&mut world, W3, 0, "a");
+    world.current_block().block_timestamp(100);
+    call(&mut world, W1).expire_task(0u64).run();
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Completed);
+}
+
+#[test]
+fn failed_task_refunds_sponsors_pro_rata() {
+    let mut world = world();
+    // 20% of the reward is split between the workers that revealed, whatever the outcome
+    post_task(
+        &mut world,
+        TaskTerms {
+            max_workers: 2,
+            participation_bps: 2_000,
+            ..TaskTerms::default()
+        },
+    );
+    call(&mut world, OWNER).increase_reward(0u64).egld(300u64).run();
+    call(&mut world, CREATOR).increase_reward(0u64).egld(100u64).run();
+
+    // a tie between 2 workers fails, 100 goes to participation and 400 is refunded
+    commit(&mut world, W1, 0, "a", 0);
+    commit(&mut world, W2, 0, "b", 0);
+    reveal(&mut world, W1, 0, "a");
+    reveal(&mut world, W2, 0, "b");
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Failed);
+
+    // the owner put in 300 of 500 and gets 3/5 of the refund, the creator the other 2/5
+    claim_all(&mut world);
+    world.check_account(OWNER).balance(BALANCE - 300 + 240);
+    world.check_account(CREATOR).balance(BALANCE - 200 + 160);
+    world.check_account(W1).balance(BALANCE + 50);
+    world.check_account(W2).balance(BALANCE + 50);
+    world.check_account(SC).balance(0u64);
+}
+
+// one extra worker for at most one round, 20% of the reward is held back for it
+fn post_escalating_task(world: &mut ScenarioWorld, max_workers: usize, reward: u64) {
+    post_task(
+        world,
+        TaskTerms {
+            max_workers,
+            escalation: proxy::EscalationPolicy {
+                extra_workers: 1,
+                max_rounds: 1,
+                reserve_bps: 2_000,
+            },
+            reward,
+            ..TaskTerms::default()
+        },
+    );
+}
+
+#[test]
+fn tie_escalates_to_an_extra_worker_paid_from_the_reserve() {
+    let mut world = world();
+    post_escalating_task(&mut world, 2, 100);
+    commit(&mut world, W1, 0, "a", 0);
+    commit(&mut world, W2, 0, "b", 0);
+    reveal(&mut world, W1, 0, "a");
+    reveal(&mut world, W2, 0, "b");
+
+    // the tie re-opens the task with one more seat, the revealed votes still count
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Open);
+    let task = world
+        .query()
+        .to(SC)
+        .typed(proxy::DistributedComputingProxy)
+        .get_task(0u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!((task.max_workers, task.escalation_round), (3, 1));
+
+    commit(&mut world, W3, 0, "a", 0);
+    reveal(&mut world, W3, 0, "a");
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Completed);
+
+    // the reserve was used, w1 and w3 split the whole reward
+    claim_all(&mut world);
+    world.check_account(W1).balance(BALANCE + 50);
+    world.check_account(W3).balance(BALANCE + 50);
+    world.check_account(CREATOR).balance(BALANCE - 100);
+    world.check_account(SC).balance(0u64);
+}
+
+#[test]
+fn unused_escalation_reserve_is_refunded() {
+    let mut world = world();
+    post_escalating_task(&mut world, 1, 100);
+    commit(&mut world, W1, 0, "a", 0);
+    reveal(&mut world, W1, 0, "a");
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Completed);
+
+    // consensus in the first round, the 20 held back for escalation go back to the creator
+    claim_all(&mut world);
+    world.check_account(W1).balance(BALANCE + 80);
+    world.check_account(CREATOR).balance(BALANCE - 80);
+    world.check_account(SC).balance(0u64);
+}
+
+const DISPUTE_BOND: u64 = 7;
+
+// 3 seats with a 100s dispute period, 30% participation and a stake of 10, w1 and w2 agree on "a" and w3 says "b"
+fn verified_task_with_arbiter(world: &mut ScenarioWorld) {
+    world.account(ARBITER).nonce(1).balance(BALANCE);
+    call(world, OWNER).set_arbiter(ARBITER.to_managed_address()).run();
+    call(world, OWNER).set_dispute_bond(BigUint::from(DISPUTE_BOND)).run();
+    post_task(
+        world,
+        TaskTerms {
+            max_workers: 3,
+            worker_stake: 10,
+            participation_bps: 3_000,
+            dispute_period: 100,
+            reward: 300,
+            ..TaskTerms::default()
+        },
+    );
+    commit(world, W1, 0, "a", 10);
+    commit(world, W2, 0, "a", 10);
+    commit(world, W3, 0, "b", 10);
+    reveal(world, W1, 0, "a");
+    reveal(world, W2, 0, "a");
+    reveal(world, W3, 0, "b");
+    assert_eq!(status(world, 0), proxy::TaskStatus::Verified);
+}
+
+#[test]
+fn undisputed_result_is_settled_after_the_dispute_period() {
+    let mut world = world();
+    verified_task_with_arbiter(&mut world);
+    call(&mut world, W1)
+        .settle_task(0u64)
+        .returns(ExpectError(4, "E_DISPUTE_PERIOD_ACTIVE"))
+        .run();
+
+    world.current_block().block_timestamp(100);
+    call(&mut world, CREATOR)
+        .dispute_result(0u64)
+        .egld(DISPUTE_BOND)
+        .returns(ExpectError(4, "E_DISPUTE_PERIOD_ENDED"))
+        .run();
+    call(&mut world, W1).settle_task(0u64).run();
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Completed);
+
+    // 30 of participation to each revealed worker, w1 and w2 split the other 210 and w3's stake goes to the creator
+    claim_all(&mut world);
+    world.check_account(W1).balance(BALANCE + 30 + 105);
+    world.check_account(W2).balance(BALANCE + 30 + 105);
+    world.check_account(W3).balance(BALANCE + 30 - 10);
+    world.check_account(CREATOR).balance(BALANCE - 300 + 10);
+    world.check_account(SC).balance(0u64);
+}
+
+#[test]
+fn confirmed_dispute_pays_out_and_sends_the_bond_to_the_treasury() {
+    let mut world = world();
+    verified_task_with_arbiter(&mut world);
+    call(&mut world, CREATOR)
+        .dispute_result(0u64)
+        .egld(DISPUTE_BOND - 1)
+        .returns(ExpectError(4, "E_WRONG_DISPUTE_BOND"))
+        .run();
+    call(&mut world, CREATOR).dispute_result(0u64).egld(DISPUTE_BOND).run();
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Disputed);
+
+    call(&mut world, CREATOR)
+        .resolve_dispute(0u64, false)
+        .returns(ExpectError(4, "E_NOT_ARBITER"))
+        .run();
+    call(&mut world, ARBITER).resolve_dispute(0u64, false).run();
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Completed);
+
+    claim_all(&mut world);
+    world.check_account(OWNER).balance(BALANCE + DISPUTE_BOND);
+    world.check_account(W1).balance(BALANCE + 30 + 105);
+    world.check_account(SC).balance(0u64);
+}
+
+#[test]
+fn reversed_dispute_refunds_the_reward_and_slashes_the_agreeing_workers() {
+    let mut world = world();
+    verified_task_with_arbiter(&mut world);
+    call(&mut world, CREATOR).dispute_result(0u64).egld(DISPUTE_BOND).run();
+    call(&mut world, ARBITER).resolve_dispute(0u64, true).run();
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Failed);
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::DistributedComputingProxy)
+        .get_task_result(0u64)
+        .returns(ExpectError(4, "E_NO_RESULT"))
+        .run();
+
+    // every revealed worker keeps its 30 of participation, the rest of the reward, the bond
+    // and the stakes of w1 and w2 go back to the creator, w3 gets its stake back
+    claim_all(&mut world);
+    world.check_account(W1).balance(BALANCE + 30 - 10);
+    world.check_account(W2).balance(BALANCE + 30 - 10);
+    world.check_account(W3).balance(BALANCE + 30);
+    world.check_account(CREATOR).balance(BALANCE - 300 + 210 + 20);
+    world.check_account(SC).balance(0u64);
+}
+
+#[test]
+fn finished_task_is_pruned_in_batches() {
+    let mut world = world();
+    let names: Vec<String> = (0..45).map(|i| format!("worker{i}")).collect();
+    let workers: Vec<TestAddress> = names.iter().map(|name| TestAddress::new(name)).collect();
+    for worker in &workers {
+        world.account(*worker).nonce(1).balance(BALANCE);
+    }
+
+    post_task(
+        &mut world,
+        TaskTerms {
+            max_workers: workers.len(),
+            reward: 450,
+            ..TaskTerms::default()
+        },
+    );
+    for worker in &workers {
+        commit(&mut world, *worker, 0, "a", 0);
+    }
+    call(&mut world, OWNER)
+        .prune_task(0u64)
+        .returns(ExpectError(4, "E_TASK_NOT_FINISHED"))
+        .run();
+    for worker in &workers {
+        reveal(&mut world, *worker, 0, "a");
+    }
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Completed);
+
+    // 20 workers per call, the call returns how many are left
+    for left in [25usize, 5, 0] {
+        let remaining = call(&mut world, OWNER).prune_task(0u64).returns(ReturnsResult).run();
+        assert_eq!(remaining, left);
+    }
+    // pruning an already pruned task is a no-op
+    let remaining = call(&mut world, OWNER).prune_task(0u64).returns(ReturnsResult).run();
+    assert_eq!(remaining, 0);
+
+    // the outcome is kept and every worker can still withdraw its reward
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Completed);
+    let result = world
+        .query()
+        .to(SC)
+        .typed(proxy::DistributedComputingProxy)
+        .get_task_result(0u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(result.agreeing_workers.len(), workers.len());
+    for worker in &workers {
+        call(&mut world, *worker).claim_rewards().run();
+        world.check_account(*worker).balance(BALANCE + 10);
+    }
+    world.check_account(SC).balance(0u64);
+}
+
+// postTasks with the shared settings of `terms`, one task per (max_workers, reward share) entry
+// the spec, max_workers and reward of `terms` are not used, the payment is added by the caller
+fn post_tasks_call<'w>(
+    world: &'w mut ScenarioWorld,
+    terms: TaskTerms,
+    entries: &[(usize, u64)],
+) -> TxTypedCall<ScenarioEnvExec<'w>, TestAddress<'static>, TestSCAddress<'static>, (), (), MultiValue2<u64, u64>> {
+    let mut batch = MultiValueVec::new();
+    for (max_workers, reward_share) in entries {
+        batch.push(MultiValue3::from((spec(), *max_workers, BigUint::<StaticApi>::from(*reward_share))));
+    }
+    call(world, CREATOR).post_tasks(
+        terms.consensus_policy,
+        terms.duration,
+        terms.reveal_duration,
+        terms.lease_duration,
+        terms.worker_stake,
+        terms.min_reputation,
+        terms.participation_bps,
+        terms.escalation,
+        terms.dispute_period,
+        terms.access,
+        batch,
+    )
+}
+
+#[test]
+fn batch_posting_returns_the_range_of_created_tasks() {
+    let mut world = world();
+    post_task(&mut world, TaskTerms::default());
+
+    post_tasks_call(&mut world, TaskTerms::default(), &[])
+        .egld(100u64)
+        .returns(ExpectError(4, "E_EMPTY_BATCH"))
+        .run();
+    post_tasks_call(&mut world, TaskTerms::default(), &[(1, 100), (3, 200)])
+        .egld(250u64)
+        .returns(ExpectError(4, "E_SHARES_MISMATCH"))
+        .run();
+
+    let range = post_tasks_call(&mut world, TaskTerms::default(), &[(1, 100), (3, 200), (2, 50)])
+        .egld(350u64)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(range.into_tuple(), (1, 3));
+
+    // every task gets its own reward share and seat count, the other settings are shared
+    for (task_id, max_workers, reward) in [(1u64, 1usize, 100u64), (2, 3, 200), (3, 2, 50)] {
+        let task = world
+            .query()
+            .to(SC)
+            .typed(proxy::DistributedComputingProxy)
+            .get_task(task_id)
+            .returns(ReturnsResult)
+            .run();
+        assert_eq!(task.max_workers, max_workers);
+        assert_eq!(task.reward_amount, BigUint::from(reward));
+        assert_eq!(task.status, proxy::TaskStatus::Open);
+    }
+    assert_eq!(funds_audit(&mut world), (0, 450, 0));
+}
+
+fn private(allow_list: &[TestAddress], worker_group: &str) -> proxy::TaskAccess<StaticApi> {
+    let mut access = public();
+    for worker in allow_list {
+        access.allow_list.push(worker.to_managed_address());
+    }
+    access.worker_group = ManagedBuffer::from(worker_group);
+    access
+}
+
+#[test]
+fn private_task_only_admits_listed_and_group_workers() {
+    let mut world = world();
+    call(&mut world, CREATOR)
+        .add_workers_to_group(ManagedBuffer::from("rigs"), MultiValueVec::from(vec![W2.to_managed_address()]))
+        .run();
+    post_task(
+        &mut world,
+        TaskTerms {
+            max_workers: 3,
+            access: private(&[W1], "rigs"),
+            ..TaskTerms::default()
+        },
+    );
+
+    call(&mut world, W3)
+        .claim_task(0u64)
+        .returns(ExpectError(4, "E_WORKER_NOT_ALLOWED"))
+        .run();
+    call(&mut world, W3)
+        .submit_result(0u64, commitment("a", W3))
+        .returns(ExpectError(4, "E_WORKER_NOT_ALLOWED"))
+        .run();
+    commit(&mut world, W1, 0, "a", 0);
+    commit(&mut world, W2, 0, "a", 0);
+
+    // listed and then removed again before claiming a seat
+    call(&mut world, CREATOR)
+        .add_to_allow_list(0u64, MultiValueVec::from(vec![W3.to_managed_address()]))
+        .run();
+    call(&mut world, CREATOR)
+        .remove_from_allow_list(0u64, MultiValueVec::from(vec![W3.to_managed_address()]))
+        .run();
+    call(&mut world, W3)
+        .submit_result(0u64, commitment("a", W3))
+        .returns(ExpectError(4, "E_WORKER_NOT_ALLOWED"))
+        .run();
+    call(&mut world, W1)
+        .add_to_allow_list(0u64, MultiValueVec::from(vec![W3.to_managed_address()]))
+        .returns(ExpectError(4, "E_NOT_TASK_CREATOR"))
+        .run();
+}
+
+#[test]
+fn claimed_seat_survives_access_edits() {
+    let mut world = world();
+    call(&mut world, CREATOR)
+        .add_workers_to_group(ManagedBuffer::from("rigs"), MultiValueVec::from(vec![W2.to_managed_address()]))
+        .run();
+    post_task(
+        &mut world,
+        TaskTerms {
+            max_workers: 2,
+            worker_stake: 100,
+            ..TaskTerms::default()
+        },
+    );
+    post_task(
+        &mut world,
+        TaskTerms {
+            worker_stake: 100,
+            access: private(&[], "rigs"),
+            ..TaskTerms::default()
+        },
+    );
+    call(&mut world, W1).claim_task(0u64).egld(100u64).run();
+    call(&mut world, W2).claim_task(1u64).egld(100u64).run();
+
+    // listing somebody else makes task 0 private, w2 leaves the group of task 1
+    call(&mut world, CREATOR)
+        .add_to_allow_list(0u64, MultiValueVec::from(vec![W3.to_managed_address()]))
+        .run();
+    call(&mut world, CREATOR)
+        .remove_workers_from_group(ManagedBuffer::from("rigs"), MultiValueVec::from(vec![W2.to_managed_address()]))
+        .run();
+
+    // both still commit on their lease and get their stake back with the reward
+    commit(&mut world, W1, 0, "a", 0);
+    commit(&mut world, W3, 0, "a", 100);
+    commit(&mut world, W2, 1, "a", 0);
+    reveal(&mut world, W1, 0, "a");
+    reveal(&mut world, W3, 0, "a");
+    reveal(&mut world, W2, 1, "a");
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Completed);
+    assert_eq!(status(&mut world, 1), proxy::TaskStatus::Completed);
+
+    claim_all(&mut world);
+    world.check_account(W1).balance(BALANCE + 50);
+    world.check_account(W2).balance(BALANCE + 100);
+    world.check_account(CREATOR).balance(BALANCE - 200);
+    world.check_account(SC).balance(0u64);
+}
+
+fn free_seats(world: &mut ScenarioWorld, task_id: u64) -> usize {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::DistributedComputingProxy)
+        .get_free_seats(task_id)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn lease_reserves_a_seat_and_carries_its_stake_to_the_commit() {
+    let mut world = world();
+    post_task(
+        &mut world,
+        TaskTerms {
+            max_workers: 2,
+            worker_stake: 100,
+            ..TaskTerms::default()
+        },
+    );
+
+    call(&mut world, W1)
+        .claim_task(0u64)
+        .egld(99u64)
+        .returns(ExpectError(4, "E_WRONG_STAKE"))
+        .run();
+    let lease_expiry = call(&mut world, W1).claim_task(0u64).egld(100u64).returns(ReturnsResult).run();
+    assert_eq!(lease_expiry, 20);
+    call(&mut world, W1)
+        .claim_task(0u64)
+        .egld(100u64)
+        .returns(ExpectError(4, "E_LEASE_ALREADY_HELD"))
+        .run();
+    commit(&mut world, W2, 0, "a", 100);
+    assert_eq!(free_seats(&mut world, 0), 0);
+    call(&mut world, W3)
+        .submit_result(0u64, commitment("a", W3))
+        .egld(100u64)
+        .returns(ExpectError(4, "E_NO_FREE_SEATS"))
+        .run();
+
+    // the stake is already held by the lease
+    call(&mut world, W1)
+        .submit_result(0u64, commitment("a", W1))
+        .egld(100u64)
+        .returns(ExpectError(4, "E_WRONG_STAKE"))
+        .run();
+    commit(&mut world, W1, 0, "a", 0);
+    reveal(&mut world, W1, 0, "a");
+    reveal(&mut world, W2, 0, "a");
+
+    claim_all(&mut world);
+    world.check_account(W1).balance(BALANCE + 50);
+    world.check_account(W2).balance(BALANCE + 50);
+    world.check_account(SC).balance(0u64);
+}
+
+#[test]
+fn lapsed_lease_frees_the_seat_and_forfeits_the_stake() {
+    let mut world = world();
+    post_task(
+        &mut world,
+        TaskTerms {
+            worker_stake: 100,
+            ..TaskTerms::default()
+        },
+    );
+    call(&mut world, W1).claim_task(0u64).egld(100u64).run();
+    call(&mut world, W2)
+        .claim_task(0u64)
+        .egld(100u64)
+        .returns(ExpectError(4, "E_NO_FREE_SEATS"))
+        .run();
+
+    // the lease ran out at 20, the next claim releases it
+    world.current_block().block_timestamp(20);
+    assert_eq!(free_seats(&mut world, 0), 1);
+    call(&mut world, W2).claim_task(0u64).egld(100u64).run();
+    call(&mut world, W1)
+        .submit_result(0u64, commitment("a", W1))
+        .egld(100u64)
+        .returns(ExpectError(4, "E_NO_FREE_SEATS"))
+        .run();
+
+    claim_all(&mut world);
+    world.check_account(W1).balance(BALANCE - 100);
+    world.check_account(CREATOR).balance(BALANCE - 100 + 100);
+}
+
+#[test]
+fn huge_durations_saturate_instead_of_wrapping_around() {
+    let mut world = world();
+    world.current_block().block_timestamp(100);
+    post_task(
+        &mut world,
+        TaskTerms {
+            worker_stake: 100,
+            lease_duration: u64::MAX,
+            ..TaskTerms::default()
+        },
+    );
+    post_task(
+        &mut world,
+        TaskTerms {
+            duration: u64::MAX,
+            reveal_duration: u64::MAX,
+            dispute_period: u64::MAX,
+            ..TaskTerms::default()
+        },
+    );
+
+    // the lease is capped at the commit deadline, it does not start out expired
+    let lease_expiry = call(&mut world, W1).claim_task(0u64).egld(100u64).returns(ReturnsResult).run();
+    assert_eq!(lease_expiry, 150);
+    commit(&mut world, W1, 0, "a", 0);
+    reveal(&mut world, W1, 0, "a");
+    claim_all(&mut world);
+    world.check_account(W1).balance(BALANCE + 100);
+
+    let task = world
+        .query()
+        .to(SC)
+        .typed(proxy::DistributedComputingProxy)
+        .get_task(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!((task.commit_deadline, task.deadline), (u64::MAX, u64::MAX));
+    commit(&mut world, W2, 1, "a", 0);
+    reveal(&mut world, W2, 1, "a");
+    assert_eq!(status(&mut world, 1), proxy::TaskStatus::Verified);
+    call(&mut world, W2)
+        .settle_task(1u64)
+        .returns(ExpectError(4, "E_DISPUTE_PERIOD_ACTIVE"))
+        .run();
+}
+
+#[test]
+fn pause_blocks_new_work_but_lease_holders_can_still_commit() {
+    let mut world = world();
+    post_task(
+        &mut world,
+        TaskTerms {
+            max_workers: 2,
+            worker_stake: 100,
+            ..TaskTerms::default()
+        },
+    );
+    call(&mut world, W1).claim_task(0u64).egld(100u64).run();
+
+    call(&mut world, W1).pause().returns(ExpectError(4, "Endpoint can only be called by owner")).run();
+    call(&mut world, OWNER).pause().run();
+    post_task_expecting(&mut world, TaskTerms::default(), ExpectError(4, "E_PAUSED"));
+    call(&mut world, W2)
+        .claim_task(0u64)
+        .egld(100u64)
+        .returns(ExpectError(4, "E_PAUSED"))
+        .run();
+    call(&mut world, W2)
+        .submit_result(0u64, commitment("a", W2))
+        .egld(100u64)
+        .returns(ExpectError(4, "E_PAUSED"))
+        .run();
+    commit(&mut world, W1, 0, "a", 0);
+
+    call(&mut world, OWNER).unpause().run();
+    commit(&mut world, W2, 0, "a", 100);
+    reveal(&mut world, W1, 0, "a");
+    reveal(&mut world, W2, 0, "a");
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Completed);
+}
+
+#[test]
+fn protocol_fee_is_fixed_at_posting_and_paid_to_the_treasury() {
+    let mut world = world();
+    call(&mut world, OWNER)
+        .set_protocol_fee(10_001u64)
+        .returns(ExpectError(4, "E_INVALID_PROTOCOL_FEE"))
+        .run();
+    call(&mut world, OWNER).set_protocol_fee(1_000u64).run();
+    call(&mut world, OWNER).set_treasury(W3.to_managed_address()).run();
+    post_task(&mut world, TaskTerms::default());
+
+    // a later change only applies to tasks posted afterwards
+    call(&mut world, OWNER).set_protocol_fee(5_000u64).run();
+    commit(&mut world, W1, 0, "a", 0);
+    reveal(&mut world, W1, 0, "a");
+
+    claim_all(&mut world);
+    world.check_account(W3).balance(BALANCE + 10);
+    world.check_account(W1).balance(BALANCE + 90);
+    world.check_account(SC).balance(0u64);
+}
+
+#[test]
+fn reveal_cannot_split_the_committed_bytes_differently() {
+    let mut world = world();
+    post_task(&mut world, TaskTerms::default());
+    call(&mut world, W1)
+        .submit_result(0u64, salted_commitment("ab", "c", W1))
+        .run();
+
+    call(&mut world, W1)
+        .reveal_result(0u64, ManagedBuffer::from("a"), ManagedBuffer::from("bc"), ManagedBuffer::new())
+        .returns(ExpectError(4, "E_COMMITMENT_MISMATCH"))
+        .run();
+    call(&mut world, W1)
+        .reveal_result(0u64, ManagedBuffer::from("ab"), ManagedBuffer::from("c"), ManagedBuffer::new())
+        .run();
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Completed);
+}
+
+#[test]
+fn dispute_left_unresolved_is_confirmed_after_the_arbitration_period() {
+    let mut world = world();
+    verified_task_with_arbiter(&mut world);
+    call(&mut world, CREATOR).dispute_result(0u64).egld(DISPUTE_BOND).run();
+
+    let arbitration_period = 7 * 24 * 60 * 60;
+    world.current_block().block_timestamp(arbitration_period - 1);
+    call(&mut world, W1)
+        .settle_task(0u64)
+        .returns(ExpectError(4, "E_ARBITRATION_PERIOD_ACTIVE"))
+        .run();
+
+    // the arbiter never shows up, anyone can release the payouts and the creator gets the bond back
+    world.current_block().block_timestamp(arbitration_period);
+    call(&mut world, W1).settle_task(0u64).run();
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Completed);
+    call(&mut world, ARBITER)
+        .resolve_dispute(0u64, true)
+        .returns(ExpectError(4, "E_TASK_NOT_DISPUTED"))
+        .run();
+
+    claim_all(&mut world);
+    world.check_account(W1).balance(BALANCE + 30 + 105);
+    world.check_account(CREATOR).balance(BALANCE - 300 + 10);
+    world.check_account(SC).balance(0u64);
+}
+
+#[test]
+fn image_digest_must_be_sha256_followed_by_64_lowercase_hex_characters() {
+    let mut world = world();
+    let valid = "sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+    let invalid = [
+        "latest",
+        "sha256:0123456789abcdef",
+        "sha512:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+        "sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdeg",
+        "sha256:0123456789ABCDEF0123456789abcdef0123456789abcdef0123456789abcdef",
+        "sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcde ",
+    ];
+    for digest in invalid {
+        let mut spec = spec();
+        spec.image_digest = ManagedBuffer::from(digest);
+        post_task_expecting(
+            &mut world,
+            TaskTerms {
+                spec,
+                ..TaskTerms::default()
+            },
+            ExpectError(4, "E_INVALID_IMAGE_DIGEST"),
+        );
+    }
+
+    let mut spec = spec();
+    spec.image_digest = ManagedBuffer::from(valid);
+    post_task(
+        &mut world,
+        TaskTerms {
+            spec,
+            ..TaskTerms::default()
+        },
+    );
+}
+
+fn reputation(world: &mut ScenarioWorld, worker: TestAddress) -> u64 {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::DistributedComputingProxy)
+        .get_worker_reputation(worker)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn working_your_own_task_earns_no_reputation() {
+    let mut world = world();
+    for worker in [CREATOR, W1] {
+        call(&mut world, worker).register_worker(ManagedBuffer::from("gpu")).run();
+    }
+
+    post_task(&mut world, TaskTerms::default());
+    commit(&mut world, CREATOR, 0, "a", 0);
+    reveal(&mut world, CREATOR, 0, "a");
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Completed);
+    assert_eq!(reputation(&mut world, CREATOR), 0);
+
+    post_task(&mut world, TaskTerms::default());
+    commit(&mut world, W1, 1, "a", 0);
+    reveal(&mut world, W1, 1, "a");
+    assert_eq!(reputation(&mut world, W1), 1);
+
+    post_task(
+        &mut world,
+        TaskTerms {
+            min_reputation: 1,
+            ..TaskTerms::default()
+        },
+    );
+    call(&mut world, CREATOR)
+        .submit_result(2u64, commitment("a", CREATOR))
+        .returns(ExpectError(4, "E_REPUTATION_TOO_LOW"))
+        .run();
+    commit(&mut world, W1, 2, "a", 0);
+}
+
+fn deadlines(world: &mut ScenarioWorld, task_id: u64) -> (u64, u64) {
+    let task = world
+        .query()
+        .to(SC)
+        .typed(proxy::DistributedComputingProxy)
+        .get_task(task_id)
+        .returns(ReturnsResult)
+        .run();
+    (task.commit_deadline, task.deadline)
+}
+
+#[test]
+fn dependent_task_opens_with_a_fresh_schedule_once_its_parents_completed() {
+    let mut world = world();
+    post_task(&mut world, TaskTerms::default());
+    post_task(&mut world, TaskTerms::default());
+    commit(&mut world, W1, 0, "a", 0);
+    reveal(&mut world, W1, 0, "a");
+    post_task_expecting(
+        &mut world,
+        TaskTerms {
+            parent_task_ids: vec![0, 0],
+            ..TaskTerms::default()
+        },
+        ExpectError(4, "E_DUPLICATE_PARENT"),
+    );
+
+    // parent 1 is still running, the dependent task waits without a schedule
+    post_task(
+        &mut world,
+        TaskTerms {
+            parent_task_ids: vec![0, 1],
+            ..TaskTerms::default()
+        },
+    );
+    assert_eq!(status(&mut world, 2), proxy::TaskStatus::Pending);
+    assert_eq!(deadlines(&mut world, 2), (0, 0));
+    call(&mut world, W2)
+        .submit_result(2u64, commitment("c", W2))
+        .returns(ExpectError(4, "E_TASK_NOT_OPEN"))
+        .run();
+    call(&mut world, W2)
+        .activate_task(2u64)
+        .returns(ExpectError(4, "E_PARENTS_NOT_FINISHED"))
+        .run();
+
+    commit(&mut world, W1, 1, "b", 0);
+    reveal(&mut world, W1, 1, "b");
+
+    // the 50s commit and reveal windows start when the task is activated, not when it was posted
+    world.current_block().block_timestamp(30);
+    call(&mut world, W2).activate_task(2u64).run();
+    assert_eq!(status(&mut world, 2), proxy::TaskStatus::Open);
+    assert_eq!(deadlines(&mut world, 2), (80, 130));
+    call(&mut world, W2)
+        .activate_task(2u64)
+        .returns(ExpectError(4, "E_TASK_NOT_PENDING"))
+        .run();
+
+    let parent_results: Vec<(u64, String)> = world
+        .query()
+        .to(SC)
+        .typed(proxy::DistributedComputingProxy)
+        .get_parent_results(2u64)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|parent_result| {
+            let (parent_id, result) = parent_result.into_tuple();
+            (parent_id, result.result_hash.to_string())
+        })
+        .collect();
+    assert_eq!(parent_results, vec![(0, "a".to_string()), (1, "b".to_string())]);
+
+    commit(&mut world, W2, 2, "c", 0);
+    reveal(&mut world, W2, 2, "c");
+    assert_eq!(status(&mut world, 2), proxy::TaskStatus::Completed);
+}
+
+#[test]
+fn dependent_task_fails_and_refunds_sponsors_when_a_parent_does_not_complete() {
+    let mut world = world();
+    post_task(&mut world, TaskTerms::default());
+    post_task(&mut world, TaskTerms::default());
+    for parent_id in [0, 1] {
+        post_task(
+            &mut world,
+            TaskTerms {
+                parent_task_ids: vec![parent_id],
+                ..TaskTerms::default()
+            },
+        );
+    }
+    call(&mut world, OWNER).increase_reward(2u64).egld(300u64).run();
+
+    // parent 0 is cancelled and parent 1 expires without a single commit
+    call(&mut world, CREATOR).cancel_task(0u64).run();
+    world.current_block().block_timestamp(100);
+    call(&mut world, W1).expire_task(1u64).run();
+    assert_eq!(status(&mut world, 1), proxy::TaskStatus::Expired);
+    post_task_expecting(
+        &mut world,
+        TaskTerms {
+            parent_task_ids: vec![1],
+            ..TaskTerms::default()
+        },
+        ExpectError(4, "E_PARENT_FAILED"),
+    );
+
+    for task_id in [2u64, 3] {
+        call(&mut world, W1).activate_task(task_id).run();
+        assert_eq!(status(&mut world, task_id), proxy::TaskStatus::Failed);
+    }
+
+    // the owner put in 300 of the 400 on task 2 and gets exactly that back, every other reward returns to the creator
+    claim_all(&mut world);
+    world.check_account(OWNER).balance(BALANCE);
+    world.check_account(CREATOR).balance(BALANCE);
+    world.check_account(SC).balance(0u64);
+}
+
+// 3 seats with a stake of 10 EGLD, rewarded with 300 TOKEN
+fn esdt_terms() -> TaskTerms {
+    TaskTerms {
+        max_workers: 3,
+        worker_stake: 10,
+        reward_token: EgldOrEsdtTokenIdentifier::esdt(TOKEN),
+        reward: 300,
+        ..TaskTerms::default()
+    }
+}
+
+#[test]
+fn esdt_reward_is_paid_in_the_token_and_stakes_stay_in_egld() {
+    let mut world = world();
+    post_task_expecting(&mut world, esdt_terms(), ExpectError(4, "E_TOKEN_NOT_ACCEPTED"));
+    call(&mut world, OWNER).add_accepted_token(TOKEN).run();
+    post_task(&mut world, esdt_terms());
+
+    // sponsors have to pay in the token of the task
+    call(&mut world, OWNER)
+        .increase_reward(0u64)
+        .egld(300u64)
+        .returns(ExpectError(4, "E_WRONG_PAYMENT_TOKEN"))
+        .run();
+    call(&mut world, OWNER).increase_reward(0u64).payment(TestEsdtTransfer(TOKEN, 0, 300)).run();
+
+    commit(&mut world, W1, 0, "a", 10);
+    commit(&mut world, W2, 0, "a", 10);
+    commit(&mut world, W3, 0, "b", 10);
+    reveal(&mut world, W1, 0, "a");
+    reveal(&mut world, W2, 0, "a");
+    reveal(&mut world, W3, 0, "b");
+    assert_eq!(status(&mut world, 0), proxy::TaskStatus::Completed);
+
+    // w1 is owed its share in TOKEN and its stake in EGLD
+    let claimable = world
+        .query()
+        .to(SC)
+        .typed(proxy::DistributedComputingProxy)
+        .get_claimable(W1.to_managed_address())
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(claimable.len(), 2);
+
+    claim_all(&mut world);
+    for winner in [W1, W2] {
+        world.check_account(winner).balance(BALANCE).esdt_balance(TOKEN, BALANCE + 300);
+    }
+    world.check_account(W3).balance(BALANCE - 10).esdt_balance(TOKEN, BALANCE);
+    world.check_account(CREATOR).balance(BALANCE + 10).esdt_balance(TOKEN, BALANCE - 300);
+    world.check_account(OWNER).balance(BALANCE).esdt_balance(TOKEN, BALANCE - 300);
+    world.check_account(SC).balance(0u64).esdt_balance(TOKEN, 0u64);
+}
+
+fn supermajority(numerator: u32, denominator: u32) -> proxy::ConsensusPolicy {
+    proxy::ConsensusPolicy::Supermajority(proxy::Fraction { numerator, denominator })
+}
+
+#[test]
+fn policies_that_two_hashes_could_both_reach_are_rejected() {
+    let mut world = world();
+    let invalid = [
+        (supermajority(1, 2), 4, NO_ESCALATION),
+        (supermajority(3, 2), 4, NO_ESCALATION),
+        (supermajority(1, 0), 4, NO_ESCALATION),
+        (proxy::ConsensusPolicy::Quorum(2), 4, NO_ESCALATION),
+        (proxy::ConsensusPolicy::Quorum(5), 4, NO_ESCALATION),
+        // 2 of 3 seats is fine, but not once escalation can grow the task to 5
+        (
+            proxy::ConsensusPolicy::Quorum(2),
+            3,
+            proxy::EscalationPolicy {
+                extra_workers: 1,
+                max_rounds: 2,
+                reserve_bps: 0,
+            },
+        ),
+    ];
+    for (consensus_policy, max_workers, escalation) in invalid {
+        post_task_expecting(
+            &mut world,
+            TaskTerms {
+                max_workers,
+                consensus_policy,
+                escalation,
+                ..TaskTerms::default()
+            },
+            ExpectError(4, "E_INCOMPATIBLE_POLICY"),
+        );
+    }
+
+    for (consensus_policy, max_workers) in [(supermajority(2, 3), 4), (proxy::ConsensusPolicy::Quorum(3), 4)] {
+        post_task(
+            &mut world,
+            TaskTerms {
+                max_workers,
+                consensus_policy,
+                ..TaskTerms::default()
+            },
+        );
+    }
+}
+
+#[test]
+fn each_policy_needs_its_own_number_of_matching_votes() {
+    let mut world = world();
+    // a simple majority would complete every one of these tasks
+    let cases = [
+        (supermajority(4, 5), ["a", "a", "a", "a", "b"].as_slice(), proxy::TaskStatus::Completed),
+        (supermajority(4, 5), ["a", "a", "a", "b", "c"].as_slice(), proxy::TaskStatus::Failed),
+        (proxy::ConsensusPolicy::Unanimity, ["a", "a", "a"].as_slice(), proxy::TaskStatus::Completed),
+        (proxy::ConsensusPolicy::Unanimity, ["a", "a", "b"].as_slice(), proxy::TaskStatus::Failed),
+        (proxy::ConsensusPolicy::Quorum(4), ["a", "a", "a", "a", "b"].as_slice(), proxy::TaskStatus::Completed),
+        (proxy::ConsensusPolicy::Quorum(4), ["a", "a", "a", "b", "b"].as_slice(), proxy::TaskStatus::Failed),
+    ];
+    for (task_id, (consensus_policy, hashes, expected)) in (0u64..).zip(cases) {
+        post_task(
+            &mut world,
+            TaskTerms {
+                max_workers: hashes.len(),
+                consensus_policy,
+                ..TaskTerms::default()
+            },
+        );
+        let workers = [W1, W2, W3, OWNER, CREATOR];
+        for (worker, hash) in workers.iter().zip(hashes) {
+            commit(&mut world, *worker, task_id, hash, 0);
+        }
+        for (worker, hash) in workers.iter().zip(hashes) {
+            reveal(&mut world, *worker, task_id, hash);
+        }
+        assert_eq!(status(&mut world, task_id), expected);
+    }
+}
+
+fn input_key(world: &mut ScenarioWorld, task_id: u64, worker: TestAddress) -> String {
+    world
+        .query()
+        .to(SC)
+        .typed(proxy::DistributedComputingProxy)
+        .get_encrypted_input_key(task_id, worker)
+        .returns(ReturnsResult)
+        .run()
+        .to_string()
+}
+
+fn attach_input_key(worker: TestAddress, encrypted_key: &str) -> MultiValue2<ManagedAddress<StaticApi>, ManagedBuffer<StaticApi>> {
+    MultiValue2::from((worker.to_managed_address(), ManagedBuffer::from(encrypted_key)))
+}
+
+#[test]
+fn input_keys_only_go_to_seat_holders_and_are_dropped_with_the_seat() {
+    let mut world = world();
+    post_task(
+        &mut world,
+        TaskTerms {
+            max_workers: 2,
+            ..TaskTerms::default()
+        },
+    );
+    call(&mut world, CREATOR)
+        .attach_input_keys(0u64, MultiValueVec::from(vec![attach_input_key(W1, "k1")]))
+        .returns(ExpectError(4, "E_WORKER_NOT_ASSIGNED"))
+        .run();
+    call(&mut world, W1).claim_task(0u64).run();
+    call(&mut world, CREATOR)
+        .attach_input_keys(0u64, MultiValueVec::from(vec![attach_input_key(W1, "k1")]))
+        .returns(ExpectError(4, "E_NO_ENCRYPTION_KEY"))
+        .run();
+
+    for worker in [W1, W2, W3] {
+        call(&mut world, worker).set_encryption_key(ManagedBuffer::from("x25519")).run();
+    }
+    call(&mut world, W2).claim_task(0u64).run();
+    call(&mut world, W1)
+        .attach_input_keys(0u64, MultiValueVec::from(vec![attach_input_key(W1, "k1")]))
+        .returns(ExpectError(4, "E_NOT_TASK_CREATOR"))
+        .run();
+    call(&mut world, CREATOR)
+        .attach_input_keys(0u64, MultiValueVec::from(vec![attach_input_key(W1, "k1"), attach_input_key(W2, "k2")]))
+        .run();
+    assert_eq!(input_key(&mut world, 0, W1), "k1");
+    assert_eq!(input_key(&mut world, 0, W2), "k2");
+
+    // w1 keeps its key with the commitment, w2 loses it when its lapsed lease is released for w3
+    commit(&mut world, W1, 0, "a", 0);
+    world.current_block().block_timestamp(20);
+    call(&mut world, W3).claim_task(0u64).run();
+    assert_eq!(input_key(&mut world, 0, W1), "k1");
+    assert_eq!(input_key(&mut world, 0, W2), "");
+    call(&mut world, CREATOR)
+        .attach_input_keys(0u64, MultiValueVec::from(vec![attach_input_key(W3, "k3")]))
+        .run();
+
+    // cancelling drops the key of the lease holder right away and pruning the ones of the committed workers
+    call(&mut world, CREATOR).cancel_task(0u64).run();
+    assert_eq!(input_key(&mut world, 0, W3), "");
+    call(&mut world, W2).prune_task(0u64).run();
+    assert_eq!(input_key(&mut world, 0, W1), "");
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            4
+// Endpoints:                           55
 // Async Callback (empty):               1
-// Total number of exported functions:   7
+// Total number of exported functions:  58
 
 #![no_std]
 
@@ -20,10 +20,61 @@ multiversx_sc_wasm_adapter::endpoints! {
     (
         init => init
         upgrade => upgrade
+        getTaskIdCounter => task_id_counter
+        getAcceptedTokens => accepted_tokens
+        addAcceptedToken => add_accepted_token
+        removeAcceptedToken => remove_accepted_token
         postTask => post_task
+        postTasks => post_tasks
+        cancelTask => cancel_task
+        increaseReward => increase_reward
+        addToAllowList => add_to_allow_list
+        removeFromAllowList => remove_from_allow_list
+        setTaskWorkerGroup => set_task_worker_group
+        attachInputKeys => attach_input_keys
+        disputeResult => dispute_result
+        resolveDispute => resolve_dispute
+        settleTask => settle_task
+        activateTask => activate_task
+        pruneTask => prune_task
+        claimTask => claim_task
         submitResult => submit_result
+        revealResult => reveal_result
+        expireTask => expire_task
+        claimRewards => claim_rewards
         getTask => get_task
         getTaskStatus => get_task_status
+        getTaskResult => get_task_result
+        getRewardContributions => get_reward_contributions
+        getTaskAllowList => get_task_allow_list
+        getEncryptedInputKey => get_encrypted_input_key
+        getParentResults => get_parent_results
+        getFreeSeats => get_free_seats
+        getTasks => get_tasks
+        getTasksByStatus => get_tasks_by_status
+        getTasksByCreator => get_tasks_by_creator
+        getTasksByWorker => get_tasks_by_worker
+        getClaimable => get_claimable
+        getFundsAudit => get_funds_audit
+        isPaused => paused
+        getProtocolFee => protocol_fee_bps
+        getTreasury => treasury
+        getArbiter => arbiter
+        getDisputeBond => dispute_bond
+        pause => pause
+        unpause => unpause
+        setProtocolFee => set_protocol_fee
+        setTreasury => set_treasury
+        setArbiter => set_arbiter
+        setDisputeBond => set_dispute_bond
+        registerWorker => register_worker
+        getWorkerEncryptionKey => worker_encryption_keys
+        setEncryptionKey => set_encryption_key
+        addWorkersToGroup => add_workers_to_group
+        removeWorkersFromGroup => remove_workers_from_group
+        getWorkerGroup => get_worker_group
+        getWorkerStats => get_worker_stats
+        getWorkerReputation => get_worker_reputation
     )
 }
 